@@ -1,8 +1,22 @@
+use std::{
+    cell::RefCell,
+    env,
+    io::{Result, Write},
+    process::{Command, Stdio},
+    rc::Rc,
+};
+
 use clap::builder::{PossibleValue, PossibleValuesParser};
+use regex::Regex;
 
 #[cfg(feature = "create-docs")]
 use crate::build_docs::build_docs;
-use crate::functions_definitions::{create_possible_fn_help_types, get_fn_help_name};
+use crate::{
+    functions_definitions::{
+        create_possible_fn_help_types, get_fn_help, get_functions_list, search_functions,
+    },
+    selection_help::get_selection_help,
+};
 
 pub fn create_possible_values() -> PossibleValuesParser {
     let mut values = create_possible_fn_help_types();
@@ -22,24 +36,140 @@ pub fn create_possible_values() -> PossibleValuesParser {
     values.into()
 }
 
-pub fn display_additional_help(help_type: &str) {
+pub struct HelpOutput {
+    writer: Rc<RefCell<dyn Write + Send>>,
+    use_pager: bool,
+}
+
+impl HelpOutput {
+    pub fn new(writer: Rc<RefCell<dyn Write + Send>>, use_pager: bool) -> Self {
+        HelpOutput { writer, use_pager }
+    }
+
+    fn show(&self, help: &[String]) -> Result<()> {
+        let text = to_terminal_text(help);
+        if self.use_pager && page(&text) {
+            return Ok(());
+        }
+        write!(self.writer.borrow_mut(), "{text}")
+    }
+}
+
+pub fn display_additional_help(help_type: &str, output: &HelpOutput) -> Result<()> {
     #[cfg(feature = "create-docs")]
     {
         if help_type == "mk-book" {
-            build_docs().unwrap();
-            return;
+            build_docs()?;
+            return Ok(());
         }
     }
     let help_type = help_type.to_lowercase();
 
-    let help = match help_type.as_str() {
-        "book" => "index".to_string(),
-        "selection" => "selection".to_string(),
-        _ => get_fn_help_name(&help_type),
+    match help_type.as_str() {
+        "book" => {
+            let root = option_env!("JAWK_BOOK_ROOT").unwrap_or("https://jawk.ykaplan.me/");
+            let url = format!("{root}index.html");
+            if open::that(&url).is_err() {
+                writeln!(output.writer.borrow_mut(), "See additional help in {url}")?;
+            }
+            Ok(())
+        }
+        "selection" => output.show(&get_selection_help()),
+        _ => output.show(&get_fn_help(&help_type)),
+    }
+}
+
+pub fn display_functions_list(output: &HelpOutput) -> Result<()> {
+    output.show(&get_functions_list())
+}
+
+pub fn display_functions_search(term: &str, output: &HelpOutput) -> Result<()> {
+    output.show(&search_functions(term))
+}
+
+fn to_terminal_text(help: &[String]) -> String {
+    let links = Regex::new(r"\[(?<text>[^\]]+)\]\([^)]+\)").unwrap();
+    let urls = Regex::new(r"\[(?<link>https://[^\]]+)\]").unwrap();
+    let mut text = String::new();
+    for line in help.iter().flat_map(|line| line.lines()) {
+        let line = links.replace_all(line, "$text");
+        let line = urls.replace_all(&line, "$link");
+        if let Some(title) = line.strip_prefix("# ") {
+            text.push_str(&format!("{title}\n{}\n", "=".repeat(title.chars().count())));
+        } else if let Some(title) = line
+            .strip_prefix("## ")
+            .or_else(|| line.strip_prefix("### "))
+        {
+            text.push_str(&format!("{title}\n{}\n", "-".repeat(title.chars().count())));
+        } else {
+            text.push_str(&line);
+            text.push('\n');
+        }
+    }
+    text
+}
+
+fn page(text: &str) -> bool {
+    let pager = env::var("PAGER").unwrap_or_else(|_| "less".to_string());
+    let mut parts = pager.split_whitespace();
+    let Some(program) = parts.next() else {
+        return false;
+    };
+    let Ok(mut child) = Command::new(program)
+        .args(parts)
+        .stdin(Stdio::piped())
+        .spawn()
+    else {
+        return false;
     };
-    let root = option_env!("JAWK_BOOK_ROOT").unwrap_or("https://jawk.ykaplan.me/");
-    let url = format!("{root}{help}.html");
-    if open::that(&url).is_err() {
-        println!("See additional help in {url}");
+    if let Some(mut stdin) = child.stdin.take() {
+        // The user might quit the pager before reading everything.
+        stdin.write_all(text.as_bytes()).ok();
+    }
+    child.wait().is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_terminal_text_underline_the_titles() {
+        let help = vec![
+            "# Title".to_string(),
+            "## Sub title".to_string(),
+            "text".to_string(),
+        ];
+
+        assert_eq!(
+            to_terminal_text(&help),
+            "Title\n=====\nSub title\n---------\ntext\n"
+        );
+    }
+
+    #[test]
+    fn to_terminal_text_remove_the_links() {
+        let help = vec![
+            "See [`take`](take.md) and [https://docs.rs/regex/latest/regex/#syntax].".to_string(),
+        ];
+
+        assert_eq!(
+            to_terminal_text(&help),
+            "See `take` and https://docs.rs/regex/latest/regex/#syntax.\n"
+        );
+    }
+
+    #[test]
+    fn show_write_the_text_without_pager() -> Result<()> {
+        let writer = Rc::new(RefCell::new(Vec::new()));
+        let output = HelpOutput::new(writer.clone(), false);
+
+        output.show(&["# Title".to_string()])?;
+
+        assert_eq!(
+            String::from_utf8(writer.borrow().clone()).unwrap(),
+            "Title\n=====\n"
+        );
+        Ok(())
     }
 }
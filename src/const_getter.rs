@@ -1,4 +1,5 @@
 use crate::{
+    explain::Explanation,
     json_parser::JsonParser,
     json_value::JsonValue,
     processor::Context,
@@ -16,6 +17,9 @@ impl Get for ConstGetters {
         let val = self.value.clone();
         Some(val)
     }
+    fn explain(&self) -> Explanation {
+        Explanation::new(format!("constant `{}`", self.value))
+    }
 }
 
 impl ConstGetters {
@@ -0,0 +1,109 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Result, Write},
+    path::Path,
+};
+
+use indexmap::IndexMap;
+
+use crate::{json_value::JsonValue, reader::Location};
+
+/// Write the records that could not be processed into a file, one JSON object per line.
+pub struct DeadLetter {
+    writer: BufWriter<File>,
+}
+
+impl DeadLetter {
+    pub fn create(path: &Path) -> Result<Self> {
+        let file = File::create(path)?;
+        Ok(DeadLetter {
+            writer: BufWriter::new(file),
+        })
+    }
+
+    pub fn write(
+        &mut self,
+        location: Option<&Location>,
+        raw: Option<&[u8]>,
+        errors: &[String],
+    ) -> Result<()> {
+        writeln!(self.writer, "{}", dead_letter_record(location, raw, errors))
+    }
+
+    pub fn flush(&mut self) -> Result<()> {
+        self.writer.flush()
+    }
+}
+
+fn dead_letter_record(
+    location: Option<&Location>,
+    raw: Option<&[u8]>,
+    errors: &[String],
+) -> JsonValue {
+    let mut record = IndexMap::with_capacity(5);
+    if let Some(location) = location {
+        if let Some(file) = &location.input {
+            record.insert("file".to_string(), file.clone().into());
+        }
+        record.insert("line".to_string(), location.line_number.into());
+        record.insert("char".to_string(), location.char_number.into());
+    }
+    if let Some(raw) = raw {
+        let raw = String::from_utf8_lossy(raw);
+        record.insert("raw".to_string(), raw.trim().to_string().into());
+    }
+    let errors = errors.iter().map(|e| e.clone().into()).collect::<Vec<_>>();
+    record.insert("errors".to_string(), errors.into());
+    record.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env::temp_dir, fs};
+
+    use super::*;
+
+    #[test]
+    fn write_add_a_line_per_record() -> Result<()> {
+        let path = temp_dir().join(format!("jawk-dead-letter-{}.json", std::process::id()));
+        let mut dead_letter = DeadLetter::create(&path)?;
+        dead_letter.write(None, Some(b"tru"), &["first".to_string()])?;
+        dead_letter.write(None, None, &["second".to_string()])?;
+        dead_letter.flush()?;
+
+        let text = fs::read_to_string(&path)?;
+        fs::remove_file(&path)?;
+        assert_eq!(
+            text,
+            "{\"raw\": \"tru\", \"errors\": [\"first\"]}\n{\"errors\": [\"second\"]}\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn dead_letter_record_include_all_the_details() {
+        let location = Location {
+            input: Some("input.json".to_string()),
+            line_number: 4,
+            char_number: 2,
+        };
+
+        let record = dead_letter_record(
+            Some(&location),
+            Some(b" {\"a\": tru} \n"),
+            &["bad".to_string()],
+        );
+
+        assert_eq!(
+            record.to_string(),
+            r#"{"file": "input.json", "line": 4, "char": 2, "raw": "{\"a\": tru}", "errors": ["bad"]}"#
+        );
+    }
+
+    #[test]
+    fn dead_letter_record_without_location() {
+        let record = dead_letter_record(None, None, &["one".to_string(), "two".to_string()]);
+
+        assert_eq!(record.to_string(), r#"{"errors": ["one", "two"]}"#);
+    }
+}
@@ -0,0 +1,68 @@
+use std::fmt::{Display, Formatter, Result};
+
+/// A node in the tree printed by `--explain`.
+pub struct Explanation {
+    title: String,
+    children: Vec<Explanation>,
+}
+
+impl Explanation {
+    pub fn new(title: impl Into<String>) -> Self {
+        Explanation {
+            title: title.into(),
+            children: Vec::new(),
+        }
+    }
+
+    pub fn with_child(mut self, child: Explanation) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn with_children(mut self, children: impl IntoIterator<Item = Explanation>) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    fn write_children(&self, f: &mut Formatter<'_>, prefix: &str) -> Result {
+        for (index, child) in self.children.iter().enumerate() {
+            let last = index + 1 == self.children.len();
+            let (branch, indent) = if last {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+            writeln!(f, "{prefix}{branch}{}", child.title)?;
+            child.write_children(f, &format!("{prefix}{indent}"))?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        writeln!(f, "{}", self.title)?;
+        self.write_children(f, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_draw_the_tree() {
+        let explanation = Explanation::new("root")
+            .with_child(
+                Explanation::new("one")
+                    .with_child(Explanation::new("one.one"))
+                    .with_child(Explanation::new("one.two")),
+            )
+            .with_child(Explanation::new("two").with_child(Explanation::new("two.one")));
+
+        assert_eq!(
+            explanation.to_string(),
+            "root\n├── one\n│   ├── one.one\n│   └── one.two\n└── two\n    └── two.one\n"
+        );
+    }
+}
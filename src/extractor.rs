@@ -1,10 +1,16 @@
-use std::{io::Read, rc::Rc};
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::Read,
+    rc::Rc,
+};
 
 use crate::{
+    explain::Explanation,
     json_value::JsonValue,
     processor::Context,
     reader::Reader,
     selection::{Get, Result, SelectionParseError},
+    strict::EvaluationError,
 };
 
 enum SingleExtract {
@@ -44,10 +50,75 @@ impl ExtractFromInput {
     }
 }
 
+impl Extract {
+    fn path(&self) -> String {
+        let mut path = "^".repeat(self.number_of_parents);
+        match &self.extract_from_input {
+            ExtractFromInput::Root => path.push('.'),
+            ExtractFromInput::Element(es) => {
+                for e in es {
+                    path.push_str(&e.to_string());
+                }
+            }
+        }
+        path
+    }
+
+    fn explain_nothing(&self, input: &JsonValue) -> Option<EvaluationError> {
+        let ExtractFromInput::Element(es) = &self.extract_from_input else {
+            return None;
+        };
+        let mut value = input.clone();
+        for e in es {
+            let next = match (e, &value) {
+                (SingleExtract::ByKey(key), JsonValue::Object(map)) => map
+                    .get(key)
+                    .cloned()
+                    .ok_or_else(|| EvaluationError::MissingKey {
+                        path: self.path(),
+                        key: key.clone(),
+                    }),
+                (SingleExtract::ByIndex(index), JsonValue::Array(list)) => list
+                    .get(*index)
+                    .cloned()
+                    .ok_or_else(|| EvaluationError::IndexOutOfBounds {
+                        path: self.path(),
+                        index: *index,
+                        length: list.len(),
+                    }),
+                (e, value) => Err(EvaluationError::UnexpectedType {
+                    path: self.path(),
+                    step: e.to_string(),
+                    expected: match e {
+                        SingleExtract::ByKey(_) => "object",
+                        SingleExtract::ByIndex(_) => "array",
+                    },
+                    actual: value.type_name(),
+                }),
+            };
+            match next {
+                Ok(next) => value = next,
+                Err(e) => return Some(e),
+            }
+        }
+        None
+    }
+}
+
 impl Get for Extract {
     fn get(&self, value: &Context) -> Option<JsonValue> {
         let input = value.parent_input(self.number_of_parents);
-        self.extract_from_input.extract(input)
+        let result = self.extract_from_input.extract(input);
+        if result.is_none()
+            && value.is_strict()
+            && let Some(error) = self.explain_nothing(input)
+        {
+            value.report(|| error);
+        }
+        result
+    }
+    fn explain(&self) -> Explanation {
+        Explanation::new(format!("extract `{}`", self.path()))
     }
 }
 
@@ -162,6 +233,15 @@ impl ExtractFromInput {
     }
 }
 
+impl Display for SingleExtract {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            SingleExtract::ByKey(key) => write!(f, ".{key}"),
+            SingleExtract::ByIndex(index) => write!(f, "#{index}"),
+        }
+    }
+}
+
 impl SingleExtract {
     fn extract(&self, value: &JsonValue) -> Option<JsonValue> {
         match value {
@@ -1,6 +1,7 @@
 use std::{rc::Rc, str::FromStr};
 
 use crate::{
+    explain::Explanation,
     json_value::JsonValue,
     processor::{Process, ProcessDecision, Result as ProcessResult},
     reader::from_string,
@@ -31,6 +32,14 @@ impl FromStr for Filter {
 }
 
 impl Filter {
+    pub fn explain(&self) -> Explanation {
+        Explanation::new("filter").with_child(self.filter.explain())
+    }
+
+    pub fn uses_raw_input(&self) -> bool {
+        self.filter.uses_raw_input()
+    }
+
     pub fn create_process(&self, next: Box<dyn Process>) -> Box<dyn Process> {
         Box::new(ActiveFilter {
             filter: self.filter.clone(),
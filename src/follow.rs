@@ -0,0 +1,160 @@
+use std::{
+    fs::{File, Metadata, metadata},
+    io::{Read, Result},
+    path::{Path, PathBuf},
+    thread::sleep,
+    time::Duration,
+};
+
+pub const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// A file that, once its end is reached, waits for more bytes to be appended to it (like `tail -f`).
+///
+/// If the file is truncated or replaced (for example, by log rotation), it is reopened from the start.
+pub struct FollowedFile {
+    path: PathBuf,
+    file: File,
+    position: u64,
+    poll_interval: Duration,
+    keep_waiting: Box<dyn FnMut() -> bool>,
+}
+
+impl FollowedFile {
+    pub fn open(path: &Path) -> Result<Self> {
+        Self::with_polling(path, POLL_INTERVAL, Box::new(|| true))
+    }
+
+    fn with_polling(
+        path: &Path,
+        poll_interval: Duration,
+        keep_waiting: Box<dyn FnMut() -> bool>,
+    ) -> Result<Self> {
+        Ok(FollowedFile {
+            path: path.to_path_buf(),
+            file: File::open(path)?,
+            position: 0,
+            poll_interval,
+            keep_waiting,
+        })
+    }
+
+    fn rotated(&self) -> Result<bool> {
+        let Ok(current) = metadata(&self.path) else {
+            // The file was moved, and the new one was not created yet.
+            return Ok(false);
+        };
+        if current.len() < self.position {
+            return Ok(true);
+        }
+        Ok(!same_file(&self.file.metadata()?, &current))
+    }
+}
+
+impl Read for FollowedFile {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        loop {
+            let read = self.file.read(buf)?;
+            if read > 0 {
+                self.position += read as u64;
+                return Ok(read);
+            }
+            if !(self.keep_waiting)() {
+                return Ok(0);
+            }
+            if self.rotated()? {
+                self.file = File::open(&self.path)?;
+                self.position = 0;
+            } else {
+                sleep(self.poll_interval);
+            }
+        }
+    }
+}
+
+#[cfg(unix)]
+fn same_file(one: &Metadata, two: &Metadata) -> bool {
+    use std::os::unix::fs::MetadataExt;
+    one.dev() == two.dev() && one.ino() == two.ino()
+}
+
+#[cfg(not(unix))]
+fn same_file(_: &Metadata, _: &Metadata) -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env::temp_dir,
+        fs::{self, OpenOptions},
+        io::Write,
+    };
+
+    use super::*;
+
+    type Change = Box<dyn FnMut(&Path)>;
+
+    fn follow(name: &str, mut changes: Vec<Change>) -> Result<String> {
+        let path = temp_dir().join(format!("jawk-follow-{}-{}.json", name, std::process::id()));
+        fs::write(&path, "1\n")?;
+        let changed_path = path.clone();
+        changes.reverse();
+        let keep_waiting = Box::new(move || {
+            if let Some(mut change) = changes.pop() {
+                change(&changed_path);
+                true
+            } else {
+                false
+            }
+        });
+        let mut file = FollowedFile::with_polling(&path, Duration::ZERO, keep_waiting)?;
+        let mut text = String::new();
+        file.read_to_string(&mut text)?;
+        fs::remove_file(&path)?;
+        Ok(text)
+    }
+
+    fn append(text: &'static str) -> Change {
+        Box::new(move |path| {
+            let mut file = OpenOptions::new().append(true).open(path).unwrap();
+            file.write_all(text.as_bytes()).unwrap();
+        })
+    }
+
+    #[test]
+    fn read_appended_bytes() -> Result<()> {
+        let text = follow(
+            "append",
+            vec![Box::new(|_| {}), append("2\n"), append("3\n")],
+        )?;
+
+        assert_eq!(text, "1\n2\n3\n");
+        Ok(())
+    }
+
+    #[test]
+    fn reopen_truncated_file() -> Result<()> {
+        let truncate: Change = Box::new(|path| fs::write(path, "").unwrap());
+
+        let text = follow("truncate", vec![truncate, Box::new(|_| {}), append("4\n")])?;
+
+        assert_eq!(text, "1\n4\n");
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn reopen_replaced_file() -> Result<()> {
+        let replace: Change = Box::new(|path| {
+            let rotated = path.with_extension("old");
+            fs::rename(path, &rotated).unwrap();
+            fs::write(path, "5\n").unwrap();
+            fs::remove_file(rotated).unwrap();
+        });
+
+        let text = follow("replace", vec![replace, Box::new(|_| {})])?;
+
+        assert_eq!(text, "1\n5\n");
+        Ok(())
+    }
+}
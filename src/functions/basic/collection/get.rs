@@ -37,6 +37,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["object", "array"])
+    .add_argument_types(&["string", "number"])
     .add_alias("[]")
     .add_description_line("Get an item from an array by index or from a map by key.")
     .add_example(
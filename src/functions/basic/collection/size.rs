@@ -22,6 +22,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array", "object", "string"])
     .add_alias("count")
     .add_alias("length")
     .add_alias("len")
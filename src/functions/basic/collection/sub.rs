@@ -77,6 +77,9 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array", "object", "string"])
+    .add_argument_types(&["number"])
+    .add_argument_types(&["number"])
         .add_description_line(
             "If the first argument is a list, creates a new list that start from the second arguments and has the size of the third argument."
         )
@@ -69,6 +69,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array", "object", "string"])
+    .add_argument_types(&["number"])
     .add_description_line("Take the last N of element in an array, object of string")
     .add_example(
         Example::new()
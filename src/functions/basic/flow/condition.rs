@@ -21,6 +21,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["boolean"])
     .add_alias("if")
     .add_description_line(
         "Return the second argument if the first argument is true. Return the third argument",
@@ -28,6 +28,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_rest_arguments_types(&["boolean"])
         .add_alias("&&")
         .add_description_line(
             "Return true if all the arguments are true, nothing if there is a non boolean argument and false if there is a false argument."
@@ -22,6 +22,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["boolean"])
     .add_alias("!")
     .add_description_line("Return false if the argument is true and true if the argument is false.")
     .add_example(Example::new().add_argument("true").expected_output("false"))
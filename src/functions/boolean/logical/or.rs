@@ -28,6 +28,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_rest_arguments_types(&["boolean"])
         .add_alias("||")
         .add_description_line(
             "Return true if any of the arguments are true, nothing if there is a non boolean argument and false if all the arguments are false."
@@ -24,6 +24,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["boolean"])
+    .add_argument_types(&["boolean"])
     .add_alias("^")
     .add_description_line("Return true if one, and only one, of the argument is true.")
     .add_example(
@@ -33,6 +33,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array"])
         .add_description_line("Filter a list.")
         .add_description_line(
             "If the first argument is a list, return all the values for which the second argument is a list."
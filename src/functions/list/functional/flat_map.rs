@@ -35,6 +35,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array"])
         .add_description_line("Flat map a list into a new list using a function.")
         .add_description_line(
             "If the first argument is a list, activate the second argument on each item, and if that returns a list, add all the items to a new list."
@@ -44,6 +44,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array"])
         .add_description_line("Fold all the items in a list into a new value.")
         .add_description_line(
             "The first item should be the list, the second one the initial value and the third one a function that create the fold."
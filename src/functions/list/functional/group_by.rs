@@ -42,6 +42,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array"])
         .add_description_line("Group items by function.")
         .add_description_line(
             "If the first argument is a list, return list grouped by the second argument."
@@ -30,6 +30,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array"])
         .add_description_line("Map a list into a new list using a function.")
         .add_description_line(
             "If the first argument is a list, activate the second argument on each item and collect into a new list."
@@ -31,6 +31,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array"])
         .add_alias("order_by")
         .add_description_line("Sort a list.")
         .add_description_line(
@@ -30,6 +30,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array"])
     .add_description_line("Check if all the items in a list are true.")
     .add_description_line("Will return false if the list is empty .")
     .add_example(
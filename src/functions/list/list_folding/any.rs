@@ -27,6 +27,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array"])
     .add_description_line("Check if any of item in a list is true.")
     .add_example(
         Example::new()
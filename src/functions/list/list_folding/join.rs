@@ -42,6 +42,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array"])
+    .add_argument_types(&["string"])
     .add_description_line("Join all the items in the list into a String.")
     .add_description_line("If list have non string items, it will return nuthing.")
     .add_description_line(
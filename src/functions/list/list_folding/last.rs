@@ -20,6 +20,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array"])
     .add_description_line("The last item in a list.")
     .add_example(
         Example::new()
@@ -34,6 +34,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array"])
     .add_description_line("Sum all the items in the list.")
     .add_description_line("If list have non numeric items, it will return nuthing.")
     .add_description_line("One can `filter` with `number?` to ensure there is a result.")
@@ -33,6 +33,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array"])
         .add_description_line(
             "Map a list into a new list where each element in the new list is an object with two elements:"
         )
@@ -33,6 +33,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array"])
     .add_alias("pop_last")
     .add_description_line("Pop the last item from a list.")
     .add_description_line(
@@ -33,6 +33,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array"])
     .add_description_line("Pop the first item from a list.")
     .add_description_line(
         "If the argument is a list, will return the list without it's first argument.",
@@ -28,6 +28,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array"])
         .add_alias("push_back")
         .add_description_line("Add items to a list.")
         .add_description_line(
@@ -26,6 +26,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array"])
         .add_description_line("Reveres the order of a list.")
         .add_description_line(
             "If the first argument is a list, will iterate over all the other arguments and add them to the list if they exists."
@@ -25,6 +25,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array"])
     .add_alias("order")
     .add_description_line("Sort a list.")
     .add_description_line("If the first argument is a list, return list sorted.")
@@ -26,6 +26,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array"])
     .add_alias("order_unique")
     .add_description_line("Sort a list and remove duplicates.")
     .add_description_line("If the first argument is a list, return list sorted without duplicates.")
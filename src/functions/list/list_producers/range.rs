@@ -30,6 +30,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["number"])
     .add_description_line("Create a new list with items from 0 to the second argument.")
     .add_description_line("If the second argument is not a positive integer, return nothing.")
     .add_description_line("Be careful not to use large numbers.")
@@ -41,6 +41,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_rest_arguments_types(&["array"])
         .add_description_line("Zip a few list into a new list.")
         .add_description_line("All the arguments must be lists.")
         .add_description_line(
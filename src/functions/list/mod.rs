@@ -2,12 +2,14 @@ mod functional;
 mod list_folding;
 mod list_manipulations;
 mod list_producers;
+mod statistics;
 
 use crate::functions_definitions::FunctionsGroup;
 use functional::group as functional;
 use list_folding::group as list_folding;
 use list_manipulations::group as list_manipluations;
 use list_producers::group as list_producers;
+use statistics::group as statistics;
 
 pub fn group() -> FunctionsGroup {
     FunctionsGroup::new("list")
@@ -15,4 +17,5 @@ pub fn group() -> FunctionsGroup {
         .add_sub_group(list_manipluations())
         .add_sub_group(functional())
         .add_sub_group(list_folding())
+        .add_sub_group(statistics())
 }
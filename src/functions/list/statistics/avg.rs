@@ -0,0 +1,40 @@
+use super::numbers::numbers;
+use std::rc::Rc;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("avg", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::Array(list)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                let numbers = numbers(&list)?;
+                if numbers.is_empty() {
+                    return None;
+                }
+                Some((numbers.iter().sum::<f64>() / numbers.len() as f64).into())
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["array"])
+    .add_alias("mean")
+    .add_description_line("Return the average (arithmetic mean) of the numbers in the list.")
+    .add_description_line("If the list has non numeric items, it will return nothing.")
+    .add_example(
+        Example::new()
+            .add_argument("[1, 2, 3, 4]")
+            .expected_output("2.5"),
+    )
+    .add_example(Example::new().add_argument("[7]").expected_output("7"))
+    .add_example(Example::new().add_argument("[]"))
+    .add_example(Example::new().add_argument("[\"1\"]"))
+}
@@ -0,0 +1,60 @@
+use indexmap::IndexMap;
+
+use std::rc::Rc;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("count_by", 2, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::Array(list)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                let mut counts = IndexMap::new();
+                for item in list {
+                    let value = value.with_inupt(item);
+                    let Some(JsonValue::String(key)) = self.0.apply(&value, 1) else {
+                        return None;
+                    };
+                    *counts.entry(key).or_insert(0usize) += 1;
+                }
+                Some(
+                    counts
+                        .into_iter()
+                        .map(|(key, count)| (key, count.into()))
+                        .collect::<IndexMap<String, JsonValue>>()
+                        .into(),
+                )
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["array"])
+    .add_description_line("Count the items of a list by a key.")
+    .add_description_line("If the first argument is a list, return an object from each key (the second argument, that should return a string) to the number of items with that key.")
+    .add_description_line("See also [`group_by`](group_by.md).")
+    .add_example(
+        Example::new()
+            .add_argument("[{\"status\": \"ok\"}, {\"status\": \"error\"}, {\"status\": \"ok\"}]")
+            .add_argument(".status")
+            .expected_output("{\"ok\": 2, \"error\": 1}"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[\"a\", \"bb\", \"cc\", \"d\", \"eee\"]")
+            .add_argument("(stringify (len .))")
+            .expected_output("{\"1\": 2, \"2\": 2, \"3\": 1}"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[1, 2]")
+            .add_argument("(len .)"),
+    )
+}
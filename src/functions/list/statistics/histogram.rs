@@ -0,0 +1,92 @@
+use indexmap::IndexMap;
+
+use super::numbers::numbers;
+use std::rc::Rc;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("histogram", 2, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::Array(list)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                let Some(JsonValue::Array(boundaries)) = self.0.apply(value, 1) else {
+                    return None;
+                };
+                let numbers = numbers(&list)?;
+                let boundaries = numbers_boundaries(&boundaries)?;
+                let mut buckets = vec![0usize; boundaries.len() - 1];
+                for number in numbers {
+                    let last = boundaries.len() - 1;
+                    if number < boundaries[0] || number > boundaries[last] {
+                        continue;
+                    }
+                    let bucket = boundaries.partition_point(|boundary| *boundary <= number);
+                    buckets[bucket.clamp(1, last) - 1] += 1;
+                }
+                Some(
+                    buckets
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, count)| {
+                            let mut bucket = IndexMap::with_capacity(3);
+                            bucket.insert("from".to_string(), boundaries[index].into());
+                            bucket.insert("to".to_string(), boundaries[index + 1].into());
+                            bucket.insert("count".to_string(), count.into());
+                            bucket.into()
+                        })
+                        .collect::<Vec<JsonValue>>()
+                        .into(),
+                )
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["array"])
+    .add_argument_types(&["array"])
+    .add_description_line("Count the numbers of a list in buckets.")
+    .add_description_line("The second argument is a sorted list of (at least two) bucket boundaries. Each bucket includes its `from` boundary and excludes its `to` boundary, except for the last bucket, that includes both.")
+    .add_description_line("Numbers outside the boundaries are not counted.")
+    .add_description_line("If the list has non numeric items, it will return nothing.")
+    .add_example(
+        Example::new()
+            .add_argument("[5, 12, 18, 25, 40, 100]")
+            .add_argument("[0, 10, 20, 50]")
+            .expected_output("[{\"from\": 0, \"to\": 10, \"count\": 1}, {\"from\": 10, \"to\": 20, \"count\": 2}, {\"from\": 20, \"to\": 50, \"count\": 2}]")
+            .explain("`100` is above the last boundary, so it is not counted."),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[0, 1, 2]")
+            .add_argument("[0, 1, 2]")
+            .expected_output("[{\"from\": 0, \"to\": 1, \"count\": 1}, {\"from\": 1, \"to\": 2, \"count\": 2}]"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[1, 2]")
+            .add_argument("[5, 1]"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[1, 2]")
+            .add_argument("[1]"),
+    )
+}
+
+/// The bucket boundaries, or nothing if there are less than two or they are not sorted.
+fn numbers_boundaries(boundaries: &[JsonValue]) -> Option<Vec<f64>> {
+    let boundaries = numbers(boundaries)?;
+    if boundaries.len() < 2 || boundaries.windows(2).any(|pair| pair[0] >= pair[1]) {
+        None
+    } else {
+        Some(boundaries)
+    }
+}
@@ -0,0 +1,50 @@
+use super::numbers::numeric_arguments;
+use std::rc::Rc;
+
+use crate::{
+    functions_definitions::{Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("max", 1, usize::MAX, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let arguments = self
+                    .0
+                    .iter()
+                    .map(|argument| argument.get(value))
+                    .collect::<Option<Vec<_>>>()?;
+                numeric_arguments(arguments)?
+                    .into_iter()
+                    .max()
+                    .map(JsonValue::Number)
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["array", "number"])
+    .add_rest_arguments_types(&["number"])
+    .add_description_line(
+        "Return the largest number in the list, or if there are a few arguments, the largest of the arguments.",
+    )
+    .add_description_line("If one of the items is not a number, it will return nothing.")
+    .add_description_line("See also [`\"max\"`](__qt__max__qt__.md) for numbers as strings.")
+    .add_example(
+        Example::new()
+            .add_argument("[3, 1.5, 8]")
+            .expected_output("8"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("3")
+            .add_argument("-2")
+            .add_argument("7")
+            .expected_output("7"),
+    )
+    .add_example(Example::new().add_argument("[]"))
+    .add_example(Example::new().add_argument("[1, null]"))
+}
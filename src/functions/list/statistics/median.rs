@@ -0,0 +1,47 @@
+use super::numbers::{percentile, sorted_numbers};
+use std::rc::Rc;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("median", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::Array(list)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                let numbers = sorted_numbers(&list)?;
+                percentile(&numbers, 50.0).map(Into::into)
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["array"])
+    .add_description_line("Return the median of the numbers in the list.")
+    .add_description_line("If the list has an even number of items, the median is the average of the two middle items.")
+    .add_description_line("If the list has non numeric items, it will return nothing.")
+    .add_example(
+        Example::new()
+            .add_argument("[5, 1, 3]")
+            .expected_output("3"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[4, 1, 3, 2]")
+            .expected_output("2.5"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[]"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[1, {}]"),
+    )
+}
@@ -0,0 +1,38 @@
+mod avg;
+mod count_by;
+mod histogram;
+mod max;
+mod median;
+mod min;
+mod mode;
+mod numbers;
+mod percentile;
+mod stddev;
+mod variance;
+
+use crate::functions_definitions::FunctionsGroup;
+use avg::get as get_avg;
+use count_by::get as get_count_by;
+use histogram::get as get_histogram;
+use max::get as get_max;
+use median::get as get_median;
+use min::get as get_min;
+use mode::get as get_mode;
+use percentile::get as get_percentile;
+use stddev::get as get_stddev;
+use variance::get as get_variance;
+
+pub fn group() -> FunctionsGroup {
+    FunctionsGroup::new("statistics")
+        .add_function(get_min())
+        .add_function(get_max())
+        .add_function(get_avg())
+        .add_function(get_median())
+        .add_function(get_percentile())
+        .add_function(get_variance())
+        .add_function(get_stddev())
+        .add_function(get_mode())
+        .add_function(get_count_by())
+        .add_function(get_histogram())
+        .add_description_line("Functions to summarize a list of numbers")
+}
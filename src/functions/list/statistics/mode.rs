@@ -0,0 +1,58 @@
+use indexmap::IndexMap;
+
+use std::rc::Rc;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("mode", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::Array(list)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                let mut counts: IndexMap<JsonValue, usize> = IndexMap::new();
+                for item in list {
+                    *counts.entry(item).or_default() += 1;
+                }
+                let mut mode = None;
+                let mut max_count = 0;
+                for (item, count) in counts {
+                    if count > max_count {
+                        max_count = count;
+                        mode = Some(item);
+                    }
+                }
+                mode
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["array"])
+    .add_description_line("Return the most common item in the list.")
+    .add_description_line("The items can be of any type. If more than one item is the most common, the first of them is returned.")
+    .add_example(
+        Example::new()
+            .add_argument("[1, 3, 2, 3, 1, 3]")
+            .expected_output("3"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[\"b\", \"a\", \"a\", \"b\"]")
+            .expected_output("\"b\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[]"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("5"),
+    )
+}
@@ -0,0 +1,87 @@
+use crate::json_value::{JsonValue, NumberValue};
+
+/// The items of the list as numbers, or nothing if one of the items is not a number.
+pub fn numbers(list: &[JsonValue]) -> Option<Vec<f64>> {
+    list.iter()
+        .map(|item| match item {
+            JsonValue::Number(number) => Some(number.into()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The numbers to compare: the items of the list if the only argument is a list, or else the arguments
+/// themselves. Nothing if one of them is not a number.
+pub fn numeric_arguments(arguments: Vec<JsonValue>) -> Option<Vec<NumberValue>> {
+    let items = match <[JsonValue; 1]>::try_from(arguments) {
+        Ok([JsonValue::Array(list)]) => list,
+        Ok(argument) => argument.into(),
+        Err(arguments) => arguments,
+    };
+    items
+        .into_iter()
+        .map(|item| match item {
+            JsonValue::Number(number) => Some(number),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The items of the list as sorted numbers, or nothing if one of the items is not a number.
+pub fn sorted_numbers(list: &[JsonValue]) -> Option<Vec<f64>> {
+    let mut numbers = numbers(list)?;
+    numbers.sort_by(f64::total_cmp);
+    Some(numbers)
+}
+
+/// The percentile of sorted numbers, using linear interpolation between the closest ranks.
+pub fn percentile(sorted: &[f64], percentile: f64) -> Option<f64> {
+    if sorted.is_empty() || !(0.0..=100.0).contains(&percentile) {
+        return None;
+    }
+    let rank = percentile / 100.0 * (sorted.len() - 1) as f64;
+    let lower = sorted[rank.floor() as usize];
+    let upper = sorted[rank.ceil() as usize];
+    Some(lower + (upper - lower) * rank.fract())
+}
+
+/// The population variance of the numbers.
+pub fn variance(numbers: &[f64]) -> Option<f64> {
+    if numbers.is_empty() {
+        return None;
+    }
+    let count = numbers.len() as f64;
+    let mean = numbers.iter().sum::<f64>() / count;
+    Some(
+        numbers
+            .iter()
+            .map(|number| (number - mean).powi(2))
+            .sum::<f64>()
+            / count,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentile_interpolate_between_ranks() {
+        let sorted = [1.0, 2.0, 3.0, 4.0];
+
+        assert_eq!(percentile(&sorted, 0.0), Some(1.0));
+        assert_eq!(percentile(&sorted, 50.0), Some(2.5));
+        assert_eq!(percentile(&sorted, 100.0), Some(4.0));
+        assert_eq!(percentile(&sorted, 101.0), None);
+        assert_eq!(percentile(&[], 50.0), None);
+    }
+
+    #[test]
+    fn variance_is_population_variance() {
+        assert_eq!(
+            variance(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]),
+            Some(4.0)
+        );
+        assert_eq!(variance(&[]), None);
+    }
+}
@@ -0,0 +1,61 @@
+use super::numbers::{percentile, sorted_numbers};
+use std::rc::Rc;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("percentile", 2, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::Array(list)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                let Some(JsonValue::Number(requested)) = self.0.apply(value, 1) else {
+                    return None;
+                };
+                let numbers = sorted_numbers(&list)?;
+                percentile(&numbers, (&requested).into()).map(Into::into)
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["array"])
+    .add_argument_types(&["number"])
+    .add_description_line("Return a percentile of the numbers in the list.")
+    .add_description_line("The second argument is the percentile, between 0 and 100. Values between the ranks are linearly interpolated.")
+    .add_description_line("If the list has non numeric items, it will return nothing.")
+    .add_example(
+        Example::new()
+            .add_argument("[15, 20, 35, 40, 50]")
+            .add_argument("40")
+            .expected_output("29"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[120, 80, 100, 95, 300]")
+            .add_argument("100")
+            .expected_output("300"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[1, 2]")
+            .add_argument("50")
+            .expected_output("1.5"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[1, 2]")
+            .add_argument("101"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[]")
+            .add_argument("50"),
+    )
+}
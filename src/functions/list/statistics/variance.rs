@@ -0,0 +1,35 @@
+use super::numbers::{numbers, variance};
+use std::rc::Rc;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("variance", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::Array(list)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                let numbers = numbers(&list)?;
+                variance(&numbers).map(Into::into)
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["array"])
+    .add_description_line("Return the (population) variance of the numbers in the list.")
+    .add_description_line("If the list has non numeric items, it will return nothing.")
+    .add_example(
+        Example::new()
+            .add_argument("[2, 4, 4, 4, 5, 5, 7, 9]")
+            .expected_output("4"),
+    )
+    .add_example(Example::new().add_argument("[1, 1]").expected_output("0"))
+    .add_example(Example::new().add_argument("[]"))
+}
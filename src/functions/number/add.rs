@@ -26,6 +26,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_rest_arguments_types(&["number"])
     .add_alias("add")
     .add_alias("plus")
     .add_description_line("If all the arguments are number, add them.")
@@ -0,0 +1,53 @@
+use std::rc::Rc;
+
+use crate::{
+    functions::number::integers::{from_integer, integer_variant},
+    functions_definitions::{Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("bit_and", 2, usize::MAX, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let mut result = -1;
+                for argument in &self.0 {
+                    let Some(JsonValue::Number(number)) = argument.get(value) else {
+                        return None;
+                    };
+                    result &= integer_variant(&number)?;
+                }
+                from_integer(result)
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_rest_arguments_types(&["number"])
+    .add_alias("bitwise_and")
+    .add_description_line("Return the bitwise and of the integer arguments.")
+    .add_description_line("If one of the arguments is not an integer, it will return nothing.")
+    .add_example(
+        Example::new()
+            .add_argument("12")
+            .add_argument("10")
+            .expected_output("8"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("255")
+            .add_argument("7")
+            .add_argument("5")
+            .expected_output("5"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("-1")
+            .add_argument("6")
+            .expected_output("6")
+            .explain("Negative numbers use two's complement."),
+    )
+    .add_example(Example::new().add_argument("12").add_argument("1.5"))
+}
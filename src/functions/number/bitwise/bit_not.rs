@@ -0,0 +1,34 @@
+use std::rc::Rc;
+
+use crate::{
+    functions::number::integers::{from_integer, integer_variant},
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("bit_not", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::Number(number)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                from_integer(!integer_variant(&number)?)
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["number"])
+    .add_alias("bitwise_not")
+    .add_description_line("Return the bitwise not of the integer argument, using two's complement (so it's `-n - 1`).")
+    .add_description_line(
+        "To flip only some bits, use `bit_xor` with a mask, like `(bit_xor .flags 255)`.",
+    )
+    .add_description_line("If the argument is not an integer, it will return nothing.")
+    .add_example(Example::new().add_argument("5").expected_output("-6"))
+    .add_example(Example::new().add_argument("-1").expected_output("0"))
+    .add_example(Example::new().add_argument("5.5"))
+}
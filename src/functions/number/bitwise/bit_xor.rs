@@ -0,0 +1,45 @@
+use std::rc::Rc;
+
+use crate::{
+    functions::number::integers::{from_integer, integer_variant},
+    functions_definitions::{Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("bit_xor", 2, usize::MAX, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let mut result = 0;
+                for argument in &self.0 {
+                    let Some(JsonValue::Number(number)) = argument.get(value) else {
+                        return None;
+                    };
+                    result ^= integer_variant(&number)?;
+                }
+                from_integer(result)
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_rest_arguments_types(&["number"])
+    .add_alias("bitwise_xor")
+    .add_description_line("Return the bitwise exclusive or of the integer arguments.")
+    .add_description_line("If one of the arguments is not an integer, it will return nothing.")
+    .add_example(
+        Example::new()
+            .add_argument("12")
+            .add_argument("10")
+            .expected_output("6"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("7")
+            .add_argument("7")
+            .expected_output("0"),
+    )
+    .add_example(Example::new().add_argument("12").add_argument("null"))
+}
@@ -0,0 +1,24 @@
+mod bit_and;
+mod bit_not;
+mod bit_or;
+mod bit_xor;
+mod shift_left;
+mod shift_right;
+
+use crate::functions_definitions::FunctionsGroup;
+use bit_and::get as get_bit_and;
+use bit_not::get as get_bit_not;
+use bit_or::get as get_bit_or;
+use bit_xor::get as get_bit_xor;
+use shift_left::get as get_shift_left;
+use shift_right::get as get_shift_right;
+
+pub fn group() -> FunctionsGroup {
+    FunctionsGroup::new("bitwise")
+        .add_function(get_bit_and())
+        .add_function(get_bit_or())
+        .add_function(get_bit_xor())
+        .add_function(get_bit_not())
+        .add_function(get_shift_left())
+        .add_function(get_shift_right())
+}
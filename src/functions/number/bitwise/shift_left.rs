@@ -0,0 +1,52 @@
+use std::rc::Rc;
+
+use crate::{
+    functions::number::integers::{from_integer, integer_variant},
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("shift_left", 2, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let (Some(JsonValue::Number(number)), Some(JsonValue::Number(bits))) =
+                    (self.0.apply(value, 0), self.0.apply(value, 1))
+                else {
+                    return None;
+                };
+                let number = integer_variant(&number)?;
+                let bits = u32::try_from(integer_variant(&bits)?).ok()?;
+                if bits >= 64 {
+                    return if number == 0 { from_integer(0) } else { None };
+                }
+                from_integer(number.checked_mul(1 << bits)?)
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["number"])
+    .add_argument_types(&["number"])
+    .add_alias("shl")
+    .add_description_line("Shift the bits of the first integer argument left by the second argument.")
+    .add_description_line(
+        "If the arguments are not integers, the number of bits is negative or the result is too large, it will return nothing.",
+    )
+    .add_example(
+        Example::new()
+            .add_argument("1")
+            .add_argument("4")
+            .expected_output("16"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("-3")
+            .add_argument("2")
+            .expected_output("-12"),
+    )
+    .add_example(Example::new().add_argument("1").add_argument("64"))
+    .add_example(Example::new().add_argument("1").add_argument("-1"))
+}
@@ -0,0 +1,56 @@
+use std::rc::Rc;
+
+use crate::{
+    functions::number::integers::{from_integer, integer_variant},
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("shift_right", 2, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let (Some(JsonValue::Number(number)), Some(JsonValue::Number(bits))) =
+                    (self.0.apply(value, 0), self.0.apply(value, 1))
+                else {
+                    return None;
+                };
+                let number = integer_variant(&number)?;
+                let bits = u32::try_from(integer_variant(&bits)?).ok()?;
+                from_integer(number >> bits.min(127))
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["number"])
+    .add_argument_types(&["number"])
+    .add_alias("shr")
+    .add_description_line(
+        "Shift the bits of the first integer argument right by the second argument (negative numbers keep their sign).",
+    )
+    .add_description_line(
+        "If the arguments are not integers or the number of bits is negative, it will return nothing.",
+    )
+    .add_example(
+        Example::new()
+            .add_argument("16")
+            .add_argument("2")
+            .expected_output("4"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("-16")
+            .add_argument("3")
+            .expected_output("-2"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("5")
+            .add_argument("100")
+            .expected_output("0"),
+    )
+    .add_example(Example::new().add_argument("5").add_argument("-1"))
+}
@@ -32,6 +32,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["number"])
+    .add_argument_types(&["number"])
         .add_alias("divide")
         .add_description_line(
             "Divide the firs argument by the second argument. If the second argument is 0 will return nothing"
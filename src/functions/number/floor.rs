@@ -22,6 +22,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["number"])
     .add_description_line("If the argument is numeric, return it's floor.")
     .add_example(Example::new().add_argument("10.3").expected_output("10"))
     .add_example(Example::new().add_argument("-10.3").expected_output("-11"))
@@ -0,0 +1,82 @@
+use crate::json_value::{JsonValue, NumberValue};
+
+/// The number as an integer, if it has no fraction.
+pub fn as_integer(number: &NumberValue) -> Option<i128> {
+    match number {
+        NumberValue::Positive(number) => Some(*number as i128),
+        NumberValue::Negative(number) => Some(*number as i128),
+        NumberValue::Float(number) if number.fract() == 0.0 && number.abs() < 2f64.powi(63) => {
+            Some(*number as i128)
+        }
+        NumberValue::Float(_) => None,
+    }
+}
+
+/// The number as an integer, only if it's one of the integer variants (a float will return nothing).
+pub fn integer_variant(number: &NumberValue) -> Option<i128> {
+    match number {
+        NumberValue::Positive(number) => Some(*number as i128),
+        NumberValue::Negative(number) => Some(*number as i128),
+        NumberValue::Float(_) => None,
+    }
+}
+
+/// The integer as a JSON number, or nothing if it's too large to be represented as one.
+pub fn from_integer(number: i128) -> Option<JsonValue> {
+    if let Ok(number) = u64::try_from(number) {
+        Some(JsonValue::Number(NumberValue::Positive(number)))
+    } else if let Ok(number) = i64::try_from(number) {
+        Some(JsonValue::Number(NumberValue::Negative(number)))
+    } else {
+        None
+    }
+}
+
+/// The number as a JSON number, or nothing if it's not a number or is infinite.
+pub fn finite(number: f64) -> Option<JsonValue> {
+    number.is_finite().then(|| number.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_integer_accept_only_numbers_without_fraction() {
+        assert_eq!(
+            as_integer(&NumberValue::Positive(u64::MAX)),
+            Some(u64::MAX as i128)
+        );
+        assert_eq!(as_integer(&NumberValue::Negative(-3)), Some(-3));
+        assert_eq!(as_integer(&NumberValue::Float(4.0)), Some(4));
+        assert_eq!(as_integer(&NumberValue::Float(4.5)), None);
+        assert_eq!(as_integer(&NumberValue::Float(f64::INFINITY)), None);
+    }
+
+    #[test]
+    fn integer_variant_ignore_floats() {
+        assert_eq!(integer_variant(&NumberValue::Positive(7)), Some(7));
+        assert_eq!(integer_variant(&NumberValue::Negative(-7)), Some(-7));
+        assert_eq!(integer_variant(&NumberValue::Float(7.0)), None);
+    }
+
+    #[test]
+    fn from_integer_keep_the_number_an_integer() {
+        assert_eq!(
+            from_integer(12),
+            Some(JsonValue::Number(NumberValue::Positive(12)))
+        );
+        assert_eq!(
+            from_integer(-12),
+            Some(JsonValue::Number(NumberValue::Negative(-12)))
+        );
+        assert_eq!(from_integer(i128::MAX), None);
+    }
+
+    #[test]
+    fn finite_ignore_nan_and_infinity() {
+        assert_eq!(finite(1.5), Some(1.5.into()));
+        assert_eq!(finite(f64::NAN), None);
+        assert_eq!(finite(f64::NEG_INFINITY), None);
+    }
+}
@@ -0,0 +1,46 @@
+use std::rc::Rc;
+
+use crate::{
+    functions::number::integers::finite,
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("atan2", 2, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let (Some(JsonValue::Number(y)), Some(JsonValue::Number(x))) =
+                    (self.0.apply(value, 0), self.0.apply(value, 1))
+                else {
+                    return None;
+                };
+                let y: f64 = y.into();
+                let x: f64 = x.into();
+                finite(y.atan2(x))
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["number"])
+    .add_argument_types(&["number"])
+    .add_description_line(
+        "Return the angle (in radians) of the point whose coordinates are the second argument (x) and the first argument (y).",
+    )
+    .add_example(
+        Example::new()
+            .add_argument("1")
+            .add_argument("1")
+            .expected_output("0.7853981633974483"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("0")
+            .add_argument("-1")
+            .expected_output("3.141592653589793"),
+    )
+    .add_example(Example::new().add_argument("0").add_argument("\"1\""))
+}
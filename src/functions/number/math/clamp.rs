@@ -0,0 +1,70 @@
+use std::rc::Rc;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("clamp", 3, 3, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let (
+                    Some(JsonValue::Number(number)),
+                    Some(JsonValue::Number(min)),
+                    Some(JsonValue::Number(max)),
+                ) = (
+                    self.0.apply(value, 0),
+                    self.0.apply(value, 1),
+                    self.0.apply(value, 2),
+                )
+                else {
+                    return None;
+                };
+                if min > max {
+                    None
+                } else {
+                    Some(JsonValue::Number(number.clamp(min, max)))
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["number"])
+    .add_argument_types(&["number"])
+    .add_argument_types(&["number"])
+    .add_description_line(
+        "Return the first argument, limited to be between the second argument (the minimum) and the third argument (the maximum).",
+    )
+    .add_description_line("If the minimum is larger than the maximum, it will return nothing.")
+    .add_example(
+        Example::new()
+            .add_argument("15")
+            .add_argument("0")
+            .add_argument("10")
+            .expected_output("10"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("-2.5")
+            .add_argument("0")
+            .add_argument("10")
+            .expected_output("0"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("4")
+            .add_argument("0")
+            .add_argument("10")
+            .expected_output("4"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("4")
+            .add_argument("10")
+            .add_argument("0"),
+    )
+}
@@ -0,0 +1,77 @@
+use std::rc::Rc;
+
+use crate::{
+    functions::number::integers::{as_integer, finite, from_integer},
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("div", 2, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let (Some(JsonValue::Number(num1)), Some(JsonValue::Number(num2))) =
+                    (self.0.apply(value, 0), self.0.apply(value, 1))
+                else {
+                    return None;
+                };
+                if let (Some(num1), Some(num2)) = (as_integer(&num1), as_integer(&num2)) {
+                    if num2 == 0 {
+                        return None;
+                    }
+                    let quotient = num1.div_euclid(num2);
+                    let quotient = if num2 < 0 && num1.rem_euclid(num2) != 0 {
+                        quotient - 1
+                    } else {
+                        quotient
+                    };
+                    return from_integer(quotient);
+                }
+                let num1: f64 = num1.into();
+                let num2: f64 = num2.into();
+                if num2 == 0.0 {
+                    None
+                } else {
+                    finite((num1 / num2).floor())
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["number"])
+    .add_argument_types(&["number"])
+    .add_alias("integer_divide")
+    .add_description_line(
+        "Divide the first argument by the second argument, and round the result down to an integer.",
+    )
+    .add_description_line("If the second argument is 0 will return nothing.")
+    .add_example(
+        Example::new()
+            .add_argument("7")
+            .add_argument("2")
+            .expected_output("3"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("-7")
+            .add_argument("2")
+            .expected_output("-4")
+            .explain("The result is rounded down, not toward zero."),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("7")
+            .add_argument("-2")
+            .expected_output("-4"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("7.5")
+            .add_argument("2")
+            .expected_output("3"),
+    )
+    .add_example(Example::new().add_argument("7").add_argument("0"))
+}
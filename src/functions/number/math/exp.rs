@@ -0,0 +1,37 @@
+use std::rc::Rc;
+
+use crate::{
+    functions::number::integers::finite,
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("exp", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::Number(number)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                let number: f64 = number.into();
+                finite(number.exp())
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["number"])
+    .add_description_line(
+        "Return e (the base of the natural logarithm) raised to the power of the argument.",
+    )
+    .add_description_line("If the result is too large, it will return nothing.")
+    .add_example(Example::new().add_argument("0").expected_output("1"))
+    .add_example(
+        Example::new()
+            .add_argument("1")
+            .expected_output("2.718281828459045"),
+    )
+    .add_example(Example::new().add_argument("1000"))
+}
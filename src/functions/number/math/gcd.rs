@@ -0,0 +1,59 @@
+use std::rc::Rc;
+
+use crate::{
+    functions::number::integers::{as_integer, from_integer},
+    functions_definitions::{Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn gcd(mut one: i128, mut two: i128) -> i128 {
+    while two != 0 {
+        (one, two) = (two, one % two);
+    }
+    one.abs()
+}
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("gcd", 2, usize::MAX, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let mut result = 0;
+                for argument in &self.0 {
+                    let Some(JsonValue::Number(number)) = argument.get(value) else {
+                        return None;
+                    };
+                    result = gcd(result, as_integer(&number)?);
+                }
+                from_integer(result)
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_rest_arguments_types(&["number"])
+    .add_alias("greatest_common_divisor")
+    .add_description_line("Return the greatest common divisor of the arguments.")
+    .add_description_line("If one of the arguments is not an integer, it will return nothing.")
+    .add_example(
+        Example::new()
+            .add_argument("12")
+            .add_argument("18")
+            .expected_output("6"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("-12")
+            .add_argument("18")
+            .add_argument("8")
+            .expected_output("2"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("12")
+            .add_argument("0")
+            .expected_output("12"),
+    )
+    .add_example(Example::new().add_argument("12").add_argument("1.5"))
+}
@@ -0,0 +1,36 @@
+use std::rc::Rc;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::{JsonValue, NumberValue},
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("finite?", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                match self.0.apply(value, 0) {
+                    Some(JsonValue::Number(NumberValue::Float(number))) => {
+                        Some(number.is_finite().into())
+                    }
+                    Some(JsonValue::Number(_)) => Some(true.into()),
+                    _ => Some(false.into()),
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_alias("is_finite")
+    .add_description_line(
+        "Return true if the argument is a finite number (not infinite and not NaN), false otherwise.",
+    )
+    .add_description_line(
+        "The math functions return nothing instead of an infinite number or NaN, so one can use this to check their results.",
+    )
+    .add_example(Example::new().add_argument("1.5").expected_output("true"))
+    .add_example(Example::new().add_argument("(exp 1000)").expected_output("false"))
+    .add_example(Example::new().add_argument("\"1\"").expected_output("false"))
+}
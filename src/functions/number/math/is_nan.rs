@@ -0,0 +1,36 @@
+use std::rc::Rc;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::{JsonValue, NumberValue},
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("nan?", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                match self.0.apply(value, 0) {
+                    Some(JsonValue::Number(NumberValue::Float(number))) => {
+                        Some(number.is_nan().into())
+                    }
+                    Some(JsonValue::Number(_)) => Some(false.into()),
+                    _ => None,
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["number"])
+    .add_alias("is_nan")
+    .add_description_line("Return true if the argument is not a number (NaN), false if it's any other number.")
+    .add_description_line(
+        "JSON has no NaN, and the math functions return nothing instead of NaN, so this is mostly useful as a guard.",
+    )
+    .add_description_line("If the argument is not a number, it will return nothing.")
+    .add_example(Example::new().add_argument("1.5").expected_output("false"))
+    .add_example(Example::new().add_argument("(sqrt -1)"))
+    .add_example(Example::new().add_argument("\"NaN\""))
+}
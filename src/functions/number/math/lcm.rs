@@ -0,0 +1,60 @@
+use std::rc::Rc;
+
+use super::gcd::gcd;
+use crate::{
+    functions::number::integers::{as_integer, from_integer},
+    functions_definitions::{Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("lcm", 2, usize::MAX, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let mut result = 1;
+                for argument in &self.0 {
+                    let Some(JsonValue::Number(number)) = argument.get(value) else {
+                        return None;
+                    };
+                    let number = as_integer(&number)?;
+                    if number == 0 || result == 0 {
+                        result = 0;
+                    } else {
+                        result = (result / gcd(result, number)).checked_mul(number.abs())?;
+                    }
+                }
+                from_integer(result)
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_rest_arguments_types(&["number"])
+    .add_alias("least_common_multiple")
+    .add_description_line("Return the least common multiple of the arguments.")
+    .add_description_line(
+        "If one of the arguments is not an integer (or the result is too large), it will return nothing.",
+    )
+    .add_example(
+        Example::new()
+            .add_argument("4")
+            .add_argument("6")
+            .expected_output("12"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("-3")
+            .add_argument("5")
+            .add_argument("2")
+            .expected_output("30"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("4")
+            .add_argument("0")
+            .expected_output("0"),
+    )
+    .add_example(Example::new().add_argument("4").add_argument("\"6\""))
+}
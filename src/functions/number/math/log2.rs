@@ -0,0 +1,31 @@
+use std::rc::Rc;
+
+use crate::{
+    functions::number::integers::finite,
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("log2", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::Number(number)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                let number: f64 = number.into();
+                finite(number.log2())
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["number"])
+    .add_description_line("Return the base 2 logarithm of the argument.")
+    .add_description_line("If the argument is not positive, it will return nothing.")
+    .add_example(Example::new().add_argument("1024").expected_output("10"))
+    .add_example(Example::new().add_argument("0.5").expected_output("-1"))
+    .add_example(Example::new().add_argument("0"))
+}
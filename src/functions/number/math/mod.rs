@@ -0,0 +1,57 @@
+mod atan2;
+mod clamp;
+mod cos;
+mod div;
+mod exp;
+mod gcd;
+mod is_finite;
+mod is_nan;
+mod lcm;
+mod ln;
+mod log10;
+mod log2;
+mod pow;
+mod sign;
+mod sin;
+mod sqrt;
+mod tan;
+
+use crate::functions_definitions::FunctionsGroup;
+use atan2::get as get_atan2;
+use clamp::get as get_clamp;
+use cos::get as get_cos;
+use div::get as get_div;
+use exp::get as get_exp;
+use gcd::get as get_gcd;
+use is_finite::get as get_is_finite;
+use is_nan::get as get_is_nan;
+use lcm::get as get_lcm;
+use ln::get as get_ln;
+use log2::get as get_log2;
+use log10::get as get_log10;
+use pow::get as get_pow;
+use sign::get as get_sign;
+use sin::get as get_sin;
+use sqrt::get as get_sqrt;
+use tan::get as get_tan;
+
+pub fn group() -> FunctionsGroup {
+    FunctionsGroup::new("math")
+        .add_function(get_pow())
+        .add_function(get_sqrt())
+        .add_function(get_exp())
+        .add_function(get_ln())
+        .add_function(get_log10())
+        .add_function(get_log2())
+        .add_function(get_sin())
+        .add_function(get_cos())
+        .add_function(get_tan())
+        .add_function(get_atan2())
+        .add_function(get_div())
+        .add_function(get_gcd())
+        .add_function(get_lcm())
+        .add_function(get_sign())
+        .add_function(get_clamp())
+        .add_function(get_is_nan())
+        .add_function(get_is_finite())
+}
@@ -0,0 +1,71 @@
+use std::rc::Rc;
+
+use crate::{
+    functions::number::integers::{as_integer, finite, from_integer},
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("pow", 2, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let (Some(JsonValue::Number(base)), Some(JsonValue::Number(exponent))) =
+                    (self.0.apply(value, 0), self.0.apply(value, 1))
+                else {
+                    return None;
+                };
+                if let (Some(integer_base), Some(integer_exponent)) =
+                    (as_integer(&base), as_integer(&exponent))
+                    && let Ok(integer_exponent) = u32::try_from(integer_exponent)
+                    && let Some(result) = integer_base
+                        .checked_pow(integer_exponent)
+                        .and_then(from_integer)
+                {
+                    return Some(result);
+                }
+                let base: f64 = base.into();
+                let exponent: f64 = exponent.into();
+                finite(base.powf(exponent))
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["number"])
+    .add_argument_types(&["number"])
+    .add_alias("power")
+    .add_description_line("Return the first argument raised to the power of the second argument.")
+    .add_description_line(
+        "If both are integers and the exponent is not negative, the result is calculated as an integer, so it stays exact.",
+    )
+    .add_description_line("If the result is not a number (or too large), it will return nothing.")
+    .add_example(
+        Example::new()
+            .add_argument("2")
+            .add_argument("10")
+            .expected_output("1024"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("3")
+            .add_argument("40")
+            .expected_output("12157665459056928801")
+            .explain("Integers stay exact, even when they are too large for a float."),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("4")
+            .add_argument("0.5")
+            .expected_output("2"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("2")
+            .add_argument("-1")
+            .expected_output("0.5"),
+    )
+    .add_example(Example::new().add_argument("-8").add_argument("0.5"))
+}
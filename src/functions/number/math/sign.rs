@@ -0,0 +1,41 @@
+use std::rc::Rc;
+
+use crate::{
+    functions::number::integers::from_integer,
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::{JsonValue, NumberValue},
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("sign", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::Number(number)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                let sign = match number {
+                    NumberValue::Positive(0) => 0,
+                    NumberValue::Positive(_) => 1,
+                    NumberValue::Negative(number) => number.signum() as i128,
+                    NumberValue::Float(number) if number.is_nan() => return None,
+                    NumberValue::Float(0.0) => 0,
+                    NumberValue::Float(number) => number.signum() as i128,
+                };
+                from_integer(sign)
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["number"])
+    .add_alias("signum")
+    .add_description_line(
+        "Return 1 if the argument is positive, -1 if it's negative and 0 if it's zero.",
+    )
+    .add_example(Example::new().add_argument("12.5").expected_output("1"))
+    .add_example(Example::new().add_argument("-3").expected_output("-1"))
+    .add_example(Example::new().add_argument("0").expected_output("0"))
+    .add_example(Example::new().add_argument("\"-3\""))
+}
@@ -0,0 +1,37 @@
+use std::rc::Rc;
+
+use crate::{
+    functions::number::integers::finite,
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("sqrt", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::Number(number)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                let number: f64 = number.into();
+                finite(number.sqrt())
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["number"])
+    .add_alias("square_root")
+    .add_description_line("Return the square root of the argument.")
+    .add_description_line("If the argument is negative, it will return nothing.")
+    .add_example(Example::new().add_argument("16").expected_output("4"))
+    .add_example(
+        Example::new()
+            .add_argument("2")
+            .expected_output("1.4142135623730951"),
+    )
+    .add_example(Example::new().add_argument("-4"))
+    .add_example(Example::new().add_argument("\"4\""))
+}
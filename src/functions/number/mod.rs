@@ -1,8 +1,12 @@
 mod abs;
 mod add;
+mod bitwise;
 mod ciel;
 mod divide;
 mod floor;
+mod integers;
+mod math;
+mod radix;
 mod reminder;
 mod round;
 mod take_away;
@@ -11,9 +15,12 @@ mod times;
 use crate::functions_definitions::FunctionsGroup;
 use abs::get as get_abs;
 use add::get as get_add;
+use bitwise::group as get_bitwise;
 use ciel::get as get_ciel;
 use divide::get as get_divide;
 use floor::get as get_floor;
+use math::group as get_math;
+use radix::group as get_radix;
 use reminder::get as get_reminder;
 use round::get as get_round;
 use take_away::get as get_take_away;
@@ -30,4 +37,7 @@ pub fn group() -> FunctionsGroup {
         .add_function(get_round())
         .add_function(get_ciel())
         .add_function(get_floor())
+        .add_sub_group(get_math())
+        .add_sub_group(get_bitwise())
+        .add_sub_group(get_radix())
 }
@@ -0,0 +1,82 @@
+/// Write the integer in the radix (between 2 and 36), with lower case letters and a `-` if it's negative.
+pub fn to_radix(number: i128, radix: u32) -> Option<String> {
+    if !(2..=36).contains(&radix) {
+        return None;
+    }
+    let mut value = number.unsigned_abs();
+    let mut digits = vec![];
+    loop {
+        digits.push(char::from_digit((value % radix as u128) as u32, radix)?);
+        value /= radix as u128;
+        if value == 0 {
+            break;
+        }
+    }
+    if number < 0 {
+        digits.push('-');
+    }
+    Some(digits.into_iter().rev().collect())
+}
+
+/// Read an integer written in the radix (between 2 and 36). The text can have a sign, and, for radix 16, 8
+/// and 2, a `0x`, `0o` or `0b` prefix. The letters can be in any case.
+pub fn parse_radix(text: &str, radix: u32) -> Option<i128> {
+    if !(2..=36).contains(&radix) {
+        return None;
+    }
+    let text = text.trim();
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(text) => (true, text),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let prefix = match radix {
+        16 => Some("0x"),
+        8 => Some("0o"),
+        2 => Some("0b"),
+        _ => None,
+    };
+    let digits = prefix
+        .and_then(|prefix| {
+            text.get(..2)
+                .filter(|start| start.eq_ignore_ascii_case(prefix))
+                .map(|_| &text[2..])
+        })
+        .unwrap_or(text);
+    if digits.is_empty() || !digits.chars().all(|ch| ch.is_digit(radix)) {
+        return None;
+    }
+    let number = i128::from_str_radix(digits, radix).ok()?;
+    Some(if negative { -number } else { number })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_radix_write_the_digits() {
+        assert_eq!(to_radix(255, 16), Some("ff".to_string()));
+        assert_eq!(to_radix(-5, 2), Some("-101".to_string()));
+        assert_eq!(to_radix(0, 8), Some("0".to_string()));
+        assert_eq!(to_radix(35, 36), Some("z".to_string()));
+        assert_eq!(
+            to_radix(u64::MAX as i128, 16),
+            Some("ffffffffffffffff".to_string())
+        );
+        assert_eq!(to_radix(10, 37), None);
+    }
+
+    #[test]
+    fn parse_radix_accept_sign_and_prefix() {
+        assert_eq!(parse_radix("ff", 16), Some(255));
+        assert_eq!(parse_radix("0xFF", 16), Some(255));
+        assert_eq!(parse_radix("-0b101", 2), Some(-5));
+        assert_eq!(parse_radix("+0o17", 8), Some(15));
+        assert_eq!(parse_radix(" z ", 36), Some(35));
+        assert_eq!(parse_radix("0x10", 10), None);
+        assert_eq!(parse_radix("12", 2), None);
+        assert_eq!(parse_radix("", 10), None);
+        assert_eq!(parse_radix("--1", 10), None);
+        assert_eq!(parse_radix("0x", 16), None);
+    }
+}
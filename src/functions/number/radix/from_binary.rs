@@ -0,0 +1,41 @@
+use std::rc::Rc;
+
+use super::digits::parse_radix;
+use crate::{
+    functions::number::integers::from_integer,
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("from_binary", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::String(text)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                from_integer(parse_radix(&text, 2)?)
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_description_line(
+        "Parse a binary string into an integer. The string can have a sign and a `0b` prefix.",
+    )
+    .add_description_line("If the string is not a valid binary integer, it will return nothing.")
+    .add_example(
+        Example::new()
+            .add_argument("\"1010\"")
+            .expected_output("10"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"0b11111111\"")
+            .expected_output("255"),
+    )
+    .add_example(Example::new().add_argument("\"102\""))
+}
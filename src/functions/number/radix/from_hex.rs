@@ -0,0 +1,51 @@
+use std::rc::Rc;
+
+use super::digits::parse_radix;
+use crate::{
+    functions::number::integers::from_integer,
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("from_hex", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::String(text)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                from_integer(parse_radix(&text, 16)?)
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_description_line("Parse a hexadecimal string into an integer. The string can have a sign and a `0x` prefix, and the letters can be in any case.")
+    .add_description_line("If the string is not a valid hexadecimal integer, it will return nothing.")
+    .add_example(
+        Example::new()
+            .add_argument("\"ff\"")
+            .expected_output("255"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"0x1A\"")
+            .expected_output("26"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"-10\"")
+            .expected_output("-16"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"0xg1\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("255"),
+    )
+}
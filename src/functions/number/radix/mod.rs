@@ -0,0 +1,28 @@
+mod digits;
+mod from_binary;
+mod from_hex;
+mod parse_int;
+mod to_binary;
+mod to_hex;
+mod to_octal;
+mod to_radix;
+
+use crate::functions_definitions::FunctionsGroup;
+use from_binary::get as get_from_binary;
+use from_hex::get as get_from_hex;
+use parse_int::get as get_parse_int;
+use to_binary::get as get_to_binary;
+use to_hex::get as get_to_hex;
+use to_octal::get as get_to_octal;
+use to_radix::get as get_to_radix;
+
+pub fn group() -> FunctionsGroup {
+    FunctionsGroup::new("radix")
+        .add_function(get_to_hex())
+        .add_function(get_from_hex())
+        .add_function(get_to_binary())
+        .add_function(get_from_binary())
+        .add_function(get_to_octal())
+        .add_function(get_to_radix())
+        .add_function(get_parse_int())
+}
@@ -0,0 +1,55 @@
+use std::rc::Rc;
+
+use super::digits::parse_radix;
+use crate::{
+    functions::number::integers::{from_integer, integer_variant},
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("parse_int", 1, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::String(text)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                let radix = match self.0.apply(value, 1) {
+                    None if self.0.len() == 1 => 10,
+                    Some(JsonValue::Number(radix)) => u32::try_from(integer_variant(&radix)?).ok()?,
+                    _ => return None,
+                };
+                from_integer(parse_radix(&text, radix)?)
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["number"])
+    .add_alias("from_radix")
+    .add_description_line(
+        "Parse the string first argument into an integer, in the radix of the optional second argument (between 2 and 36, the default is 10).",
+    )
+    .add_description_line(
+        "The string can have a sign, and for radix 16, 8 or 2, a `0x`, `0o` or `0b` prefix. Letters can be in any case.",
+    )
+    .add_description_line("If the string is not a valid integer in that radix, it will return nothing.")
+    .add_example(Example::new().add_argument("\"-42\"").expected_output("-42"))
+    .add_example(
+        Example::new()
+            .add_argument("\"0o755\"")
+            .add_argument("8")
+            .expected_output("493"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"Z\"")
+            .add_argument("36")
+            .expected_output("35"),
+    )
+    .add_example(Example::new().add_argument("\"12a\""))
+    .add_example(Example::new().add_argument("\"12\"").add_argument("40"))
+}
@@ -0,0 +1,35 @@
+use std::rc::Rc;
+
+use super::digits::to_radix;
+use crate::{
+    functions::number::integers::integer_variant,
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("to_binary", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::Number(number)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                to_radix(integer_variant(&number)?, 2).map(Into::into)
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["number"])
+    .add_description_line("Return the integer argument as a binary string (without a prefix).")
+    .add_description_line("If the argument is not an integer, it will return nothing.")
+    .add_example(
+        Example::new()
+            .add_argument("10")
+            .expected_output("\"1010\""),
+    )
+    .add_example(Example::new().add_argument("0").expected_output("\"0\""))
+    .add_example(Example::new().add_argument("\"10\""))
+}
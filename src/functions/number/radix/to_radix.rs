@@ -0,0 +1,50 @@
+use std::rc::Rc;
+
+use super::digits::to_radix;
+use crate::{
+    functions::number::integers::integer_variant,
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("to_radix", 2, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let (Some(JsonValue::Number(number)), Some(JsonValue::Number(radix))) =
+                    (self.0.apply(value, 0), self.0.apply(value, 1))
+                else {
+                    return None;
+                };
+                let radix = u32::try_from(integer_variant(&radix)?).ok()?;
+                to_radix(integer_variant(&number)?, radix).map(Into::into)
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["number"])
+    .add_argument_types(&["number"])
+    .add_description_line(
+        "Return the integer first argument as a string in the radix of the second argument (between 2 and 36).",
+    )
+    .add_description_line("Digits above 9 are written as lower case letters.")
+    .add_description_line(
+        "If the arguments are not integers or the radix is not between 2 and 36, it will return nothing.",
+    )
+    .add_example(
+        Example::new()
+            .add_argument("35")
+            .add_argument("36")
+            .expected_output("\"z\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("-100")
+            .add_argument("3")
+            .expected_output("\"-10201\""),
+    )
+    .add_example(Example::new().add_argument("10").add_argument("1"))
+}
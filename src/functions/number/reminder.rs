@@ -32,6 +32,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["number"])
+    .add_argument_types(&["number"])
         .add_alias("mod")
         .add_alias("modulo")
         .add_alias("reminder")
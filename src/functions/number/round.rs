@@ -2,31 +2,77 @@ use std::rc::Rc;
 
 use crate::{
     functions_definitions::{Arguments, Example, FunctionDefinitions},
-    json_value::JsonValue,
+    json_value::{JsonValue, NumberValue},
     processor::Context,
     selection::Get,
 };
 
 pub fn get() -> FunctionDefinitions {
-    FunctionDefinitions::new("round", 1, 1, |args| {
+    FunctionDefinitions::new("round", 1, 2, |args| {
         struct Impl(Vec<Rc<dyn Get>>);
         impl Get for Impl {
             fn get(&self, value: &Context) -> Option<JsonValue> {
-                if let Some(JsonValue::Number(num)) = self.0.apply(value, 0) {
-                    let num: f64 = num.into();
-                    Some(num.round().into())
+                let Some(JsonValue::Number(num)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                let places = match self.0.apply(value, 1) {
+                    None if self.0.len() == 1 => 0,
+                    Some(JsonValue::Number(NumberValue::Positive(places))) => places.min(15) as i32,
+                    Some(JsonValue::Number(NumberValue::Negative(places))) => places.max(-15) as i32,
+                    _ => return None,
+                };
+                if places >= 0 && !matches!(num, NumberValue::Float(_)) {
+                    return Some(JsonValue::Number(num));
+                }
+                let num: f64 = num.into();
+                let factor = 10f64.powi(places);
+                let rounded = (num * factor).round() / factor;
+                if rounded.is_finite() {
+                    Some(rounded.into())
                 } else {
-                    None
+                    Some(num.into())
                 }
             }
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["number"])
+    .add_argument_types(&["number"])
     .add_description_line("If the argument is numeric, return it's rounded.")
+    .add_description_line(
+        "An optional second argument is the number of decimal places to keep (negative to round to tens, hundreds and so on).",
+    )
     .add_example(Example::new().add_argument("10.3").expected_output("10"))
     .add_example(Example::new().add_argument("-10.3").expected_output("-10"))
     .add_example(Example::new().add_argument("-10").expected_output("-10"))
     .add_example(Example::new().add_argument("-10.5").expected_output("-11"))
     .add_example(Example::new().add_argument("10.5").expected_output("11"))
+    .add_example(
+        Example::new()
+            .add_argument("3.14159")
+            .add_argument("2")
+            .expected_output("3.14"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("1250")
+            .add_argument("-2")
+            .expected_output("1300"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("12345678901234567890")
+            .add_argument("2")
+            .expected_output("12345678901234567890")
+            .explain("Integers are kept as is."),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("1e300")
+            .add_argument("15")
+            .expected_output("1e300")
+            .explain("Numbers too large to be scaled are kept as is."),
+    )
+    .add_example(Example::new().add_argument("1.5").add_argument("0.5"))
     .add_example(Example::new().add_argument("[0]"))
 }
@@ -27,6 +27,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["number"])
+    .add_argument_types(&["number"])
     .add_alias("minus")
     .add_alias("take_away")
     .add_alias("substruct")
@@ -26,6 +26,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_rest_arguments_types(&["number"])
     .add_alias("times")
     .add_alias("multiple")
     .add_description_line("If all the arguments are number, multiply them.")
@@ -1,10 +1,12 @@
 mod nas_arithmetic;
 mod nas_compare;
+mod nas_statistics;
 mod to_big_decimal;
 
 use crate::functions_definitions::FunctionsGroup;
 use nas_arithmetic::group as nas_arithmetic;
 use nas_compare::group as nas_compare;
+use nas_statistics::group as nas_statistics;
 
 pub fn group() -> FunctionsGroup {
     FunctionsGroup::new("nas")
@@ -12,4 +14,5 @@ pub fn group() -> FunctionsGroup {
         .add_description_line("Can be used for big numbers and accurate calculates.")
         .add_sub_group(nas_arithmetic())
         .add_sub_group(nas_compare())
+        .add_sub_group(nas_statistics())
 }
@@ -21,6 +21,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
     .add_alias("nas_abs")
     .add_description_line(
         "If the argument is number as string, return it's absolute value as string.",
@@ -33,6 +33,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
         .add_alias("nas_divide")
         .add_description_line(
             "Divide the firs argument by the second argument if both are strings as numbers. If the second argument is \"0\" will return nothing"
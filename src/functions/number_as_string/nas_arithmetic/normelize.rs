@@ -20,6 +20,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
     .add_alias("nas_normelize")
     .add_description_line("If the argument is string as number, return it in a normelize form (to allow a consent Uniqueness check).")
     .add_example(Example::new().add_argument("\"1000000\"").expected_output("\"1000000\""))
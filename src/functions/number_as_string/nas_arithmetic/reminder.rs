@@ -33,6 +33,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
         .add_alias("nas_mod")
         .add_alias("nas_modulo")
         .add_alias("nas_remider")
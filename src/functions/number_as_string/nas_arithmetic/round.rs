@@ -21,6 +21,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
     .add_alias("nas_round")
     .add_description_line("If the argument is string as number, return it's rounded.")
     .add_example(
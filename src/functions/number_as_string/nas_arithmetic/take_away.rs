@@ -28,6 +28,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
     .add_alias("nas_minus")
     .add_alias("nas_take_away")
     .add_alias("nas_substruct")
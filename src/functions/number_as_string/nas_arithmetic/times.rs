@@ -28,6 +28,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_rest_arguments_types(&["string"])
     .add_alias("nas_times")
     .add_alias("nas_multiple")
     .add_description_line("If all the arguments are numbers as string, multiply them.")
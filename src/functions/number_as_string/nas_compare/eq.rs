@@ -26,6 +26,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
     .add_description_line("Compare two string as numbers and return true if both are equals.")
     .add_example(
         Example::new()
@@ -26,6 +26,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
     .add_description_line(
         "Compare two numbers as string and return true if the first is greater than the second.",
     )
@@ -23,6 +23,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
     .add_description_line(
         "Compare two numbers and string and return true if the first is greater or eqauls than the second.",
     )
@@ -26,6 +26,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
     .add_alias("\"<>\"")
     .add_description_line("Compare two number as strings and return true if both are not equals.")
     .add_example(
@@ -32,6 +32,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["array"])
     .add_alias("\"order_by\"")
     .add_alias("sort_by_nas")
     .add_alias("order_by_nas")
@@ -0,0 +1,49 @@
+use bigdecimal::BigDecimal;
+
+use super::statistics::percentile;
+use std::rc::Rc;
+
+use crate::{
+    functions::number_as_string::to_big_decimal::to_big_decimals,
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("\"median\"", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::Array(list)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                let mut numbers = to_big_decimals(list)?;
+                numbers.sort();
+                percentile(&numbers, &BigDecimal::from(50)).map(Into::into)
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["array"])
+    .add_alias("nas_median")
+    .add_description_line("Return the median of the numbers as string in the list.")
+    .add_description_line("If the list has an even number of items, the median is the average of the two middle items.")
+    .add_description_line("If the list has items that are not numbers as string, it will return nothing.")
+    .add_description_line("See also [`median`](median.md) for numbers.")
+    .add_example(
+        Example::new()
+            .add_argument("[\"5\", \"1\", \"3\"]")
+            .expected_output("\"3\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[\"4\", \"1\", \"3\", \"2\"]")
+            .expected_output("\"2.5\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[]"),
+    )
+}
@@ -0,0 +1,29 @@
+mod avg;
+mod max;
+mod median;
+mod min;
+mod percentile;
+mod statistics;
+mod stddev;
+mod variance;
+
+use crate::functions_definitions::FunctionsGroup;
+use avg::get as get_avg;
+use max::get as get_max;
+use median::get as get_median;
+use min::get as get_min;
+use percentile::get as get_percentile;
+use stddev::get as get_stddev;
+use variance::get as get_variance;
+
+pub fn group() -> FunctionsGroup {
+    FunctionsGroup::new("nas_statistics")
+        .add_description_line("Number As String statistical functions.")
+        .add_function(get_min())
+        .add_function(get_max())
+        .add_function(get_avg())
+        .add_function(get_median())
+        .add_function(get_percentile())
+        .add_function(get_variance())
+        .add_function(get_stddev())
+}
@@ -0,0 +1,57 @@
+use super::statistics::percentile;
+use std::rc::Rc;
+
+use crate::{
+    functions::number_as_string::to_big_decimal::{BigDecimalConvert, to_big_decimals},
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("\"percentile\"", 2, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::Array(list)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                let mut numbers = to_big_decimals(list)?;
+                let requested = self.0.apply(value, 1).to_big_decimal()?;
+                numbers.sort();
+                percentile(&numbers, &requested).map(Into::into)
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["array"])
+    .add_argument_types(&["string"])
+    .add_alias("nas_percentile")
+    .add_description_line("Return a percentile of the numbers as string in the list.")
+    .add_description_line("The second argument is the percentile as string, between \"0\" and \"100\". Values between the ranks are linearly interpolated.")
+    .add_description_line("If the list has items that are not numbers as string, it will return nothing.")
+    .add_description_line("See also [`percentile`](percentile.md) for numbers.")
+    .add_example(
+        Example::new()
+            .add_argument("[\"15\", \"20\", \"35\", \"40\", \"50\"]")
+            .add_argument("\"40\"")
+            .expected_output("\"29\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[\"1\", \"2\"]")
+            .add_argument("\"50\"")
+            .expected_output("\"1.5\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[\"1\", \"2\"]")
+            .add_argument("50"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[\"1\", \"2\"]")
+            .add_argument("\"101\""),
+    )
+}
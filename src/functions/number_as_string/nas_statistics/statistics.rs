@@ -0,0 +1,72 @@
+use bigdecimal::{BigDecimal, RoundingMode, ToPrimitive, Zero};
+
+/// The average of the numbers, or nothing if there are no numbers.
+pub fn average(numbers: &[BigDecimal]) -> Option<BigDecimal> {
+    if numbers.is_empty() {
+        return None;
+    }
+    let sum = numbers.iter().fold(BigDecimal::zero(), |sum, n| sum + n);
+    Some(sum / BigDecimal::from(numbers.len() as u64))
+}
+
+/// The percentile of sorted numbers, using linear interpolation between the closest ranks.
+pub fn percentile(sorted: &[BigDecimal], percentile: &BigDecimal) -> Option<BigDecimal> {
+    let range = BigDecimal::zero()..=BigDecimal::from(100);
+    if sorted.is_empty() || !range.contains(percentile) {
+        return None;
+    }
+    let rank = percentile * BigDecimal::from((sorted.len() - 1) as u64) / BigDecimal::from(100);
+    let lower_rank = rank.with_scale_round(0, RoundingMode::Floor);
+    let fraction = &rank - &lower_rank;
+    let lower_index = lower_rank.to_usize()?;
+    let lower = &sorted[lower_index];
+    if fraction.is_zero() {
+        return Some(lower.clone());
+    }
+    let upper = &sorted[lower_index + 1];
+    Some(lower + (upper - lower) * fraction)
+}
+
+/// The population variance of the numbers.
+pub fn variance(numbers: &[BigDecimal]) -> Option<BigDecimal> {
+    let mean = average(numbers)?;
+    let squares: Vec<_> = numbers.iter().map(|n| (n - &mean).square()).collect();
+    average(&squares)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn numbers(numbers: &[&str]) -> Vec<BigDecimal> {
+        numbers
+            .iter()
+            .map(|n| BigDecimal::from_str(n).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn percentile_interpolate_between_ranks() {
+        let sorted = numbers(&["1", "2", "3", "4"]);
+
+        assert_eq!(
+            percentile(&sorted, &BigDecimal::from(50)),
+            Some(BigDecimal::from_str("2.5").unwrap())
+        );
+        assert_eq!(
+            percentile(&sorted, &BigDecimal::from(100)),
+            Some(BigDecimal::from(4))
+        );
+        assert_eq!(percentile(&sorted, &BigDecimal::from(-1)), None);
+    }
+
+    #[test]
+    fn variance_is_population_variance() {
+        let numbers = numbers(&["2", "4", "4", "4", "5", "5", "7", "9"]);
+
+        assert_eq!(variance(&numbers), Some(BigDecimal::from(4)));
+        assert_eq!(variance(&[]), None);
+    }
+}
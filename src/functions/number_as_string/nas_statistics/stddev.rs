@@ -0,0 +1,42 @@
+use super::statistics::variance;
+use std::rc::Rc;
+
+use crate::{
+    functions::number_as_string::to_big_decimal::to_big_decimals,
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("\"stddev\"", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::Array(list)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                let numbers = to_big_decimals(list)?;
+                variance(&numbers)?.sqrt().map(Into::into)
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["array"])
+    .add_alias("nas_stddev")
+    .add_description_line(
+        "Return the (population) standard deviation of the numbers as string in the list.",
+    )
+    .add_description_line(
+        "If the list has items that are not numbers as string, it will return nothing.",
+    )
+    .add_description_line("See also [`stddev`](stddev.md) for numbers.")
+    .add_example(
+        Example::new()
+            .add_argument("[\"2\", \"4\", \"4\", \"4\", \"5\", \"5\", \"7\", \"9\"]")
+            .expected_output("\"2\""),
+    )
+    .add_example(Example::new().add_argument("[]"))
+    .add_example(Example::new().add_argument("[\"1\", \"a\"]"))
+}
@@ -21,3 +21,11 @@ impl BigDecimalConvert for Option<JsonValue> {
         }
     }
 }
+
+/// The items of the list as numbers (from numbers as string), or nothing if one of the items is not a number as
+/// string.
+pub fn to_big_decimals(list: Vec<JsonValue>) -> Option<Vec<BigDecimal>> {
+    list.into_iter()
+        .map(|item| Some(item).to_big_decimal())
+        .collect()
+}
@@ -32,6 +32,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["object"])
         .add_description_line("Map an object values.")
         .add_description_line(
             "The first argument should be the object and the second should be a function to map the values to."
@@ -31,6 +31,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["object"])
+    .add_argument_types(&["string"])
     .add_alias("put_if_absent")
     .add_alias("replace_if_absent")
     .add_alias("{-}")
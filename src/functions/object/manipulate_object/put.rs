@@ -27,6 +27,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["object"])
+    .add_argument_types(&["string"])
     .add_alias("insert")
     .add_alias("replace")
     .add_alias("{}")
@@ -31,6 +31,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["object"])
+    .add_argument_types(&["string"])
     .add_alias("insert_if_exists")
     .add_alias("put_if_exists")
     .add_alias("{+}")
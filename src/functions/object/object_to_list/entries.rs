@@ -30,6 +30,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["object"])
         .add_alias("to_list")
         .add_description_line(
             "Get the list of all the entries of an object. Each item of the list will be an object with `key` and `value` entries"
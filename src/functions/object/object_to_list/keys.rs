@@ -23,6 +23,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["object"])
     .add_description_line("Get the list of keys from an object.")
     .add_example(
         Example::new()
@@ -21,6 +21,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["object"])
     .add_alias("vals")
     .add_description_line("Get the list of values from an object.")
     .add_example(
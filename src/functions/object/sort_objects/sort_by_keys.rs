@@ -25,6 +25,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["object"])
     .add_alias("order_by_keys")
     .add_description_line("Sort an object by it's keys.")
     .add_description_line("If the first argument is an object, return object sorted by it's keys.")
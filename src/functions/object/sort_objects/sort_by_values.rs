@@ -25,6 +25,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["object"])
     .add_alias("order_by_values")
     .add_description_line("Sort an object by it's values.")
     .add_description_line(
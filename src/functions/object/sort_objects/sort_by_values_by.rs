@@ -31,6 +31,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["object"])
         .add_alias("order_by_values_by")
         .add_description_line("Sort an object by a function to it's values.")
         .add_description_line(
@@ -12,6 +12,7 @@ use crate::{
     json_value::JsonValue,
     processor::Context,
     selection::Get,
+    strict::EvaluationError,
 };
 
 pub fn get() -> FunctionDefinitions {
@@ -19,10 +20,10 @@ pub fn get() -> FunctionDefinitions {
         struct Impl(Vec<Rc<dyn Get>>);
         impl Get for Impl {
             fn get(&self, context: &Context) -> Option<JsonValue> {
-                let Some(JsonValue::String(command)) = self.0.apply(context, 0) else {
+                let Some(JsonValue::String(name)) = self.0.apply(context, 0) else {
                     return None;
                 };
-                let mut command = Command::new(command);
+                let mut command = Command::new(&name);
                 for arg in self.0.iter().skip(1) {
                     let Some(JsonValue::String(arg)) = arg.get(context) else {
                         return None;
@@ -31,11 +32,12 @@ pub fn get() -> FunctionDefinitions {
                 }
                 command.stdout(Stdio::piped());
                 command.stderr(Stdio::piped());
-                let Ok(child) = command.spawn() else {
-                    return None;
-                };
-                let Ok(result) = child.wait_with_output() else {
-                    return None;
+                let result = match command.spawn().and_then(|child| child.wait_with_output()) {
+                    Ok(result) => result,
+                    Err(e) => {
+                        context.report(|| EvaluationError::Exec(name, e.to_string()));
+                        return None;
+                    }
                 };
                 let mut value = IndexMap::with_capacity(6);
                 value.insert("success".into(), result.status.success().into());
@@ -62,6 +64,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(vec))
     })
+    .add_rest_arguments_types(&["string"])
         .add_alias("execute")
         .add_description_line("Execute an external process and wait for it's completion.")
         .add_description_line(
@@ -29,6 +29,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
         .add_alias("base64")
         .add_description_line("Decode a BASE64 string and try to convert to a string using UTF8.")
         .add_description_line(
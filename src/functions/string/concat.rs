@@ -26,6 +26,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_rest_arguments_types(&["string"])
     .add_description_line("Concat all string arguments.")
     .add_example(
         Example::new()
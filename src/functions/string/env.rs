@@ -28,6 +28,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
     .add_alias("$")
     .add_description_line("Get environment variable.")
     .add_example(
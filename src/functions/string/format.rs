@@ -0,0 +1,100 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::str::FromStr;
+
+use crate::processor::Context;
+use crate::template::Template;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("format", 1, usize::MAX, |args| {
+        struct Impl {
+            args: Vec<Rc<dyn Get>>,
+            last: RefCell<Option<(String, Option<Rc<Template>>)>>,
+        }
+        impl Impl {
+            /// The parsed template, the last one is kept as the template is usually the same for all inputs.
+            fn template(&self, template: String) -> Option<Rc<Template>> {
+                let mut last = self.last.borrow_mut();
+                match &*last {
+                    Some((source, parsed)) if *source == template => parsed.clone(),
+                    _ => {
+                        let parsed = Template::from_str(&template).ok().map(Rc::new);
+                        *last = Some((template, parsed.clone()));
+                        parsed
+                    }
+                }
+            }
+        }
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::String(template)) = self.args.apply(value, 0) else {
+                    return None;
+                };
+                let template = self.template(template)?;
+                let arguments: Vec<_> = (1..self.args.len())
+                    .map(|index| self.args.apply(value, index))
+                    .collect();
+                let named = arguments.first().and_then(Option::as_ref);
+                template
+                    .render(named, &arguments)
+                    .map(JsonValue::String)
+            }
+        }
+        Rc::new(Impl {
+            args,
+            last: RefCell::default(),
+        })
+    })
+    .add_argument_types(&["string"])
+    .add_alias("printf")
+    .add_description_line("Build a string from a template and values.")
+    .add_description_line(
+        "The first argument is the template. Placeholders like `{name}` are looked up in the second argument (that should be an object, nested values can be looked up with `{a.b}`), while `{0}`, `{1}` or `{}` refer to the arguments after the template by their position.",
+    )
+    .add_description_line(
+        "A placeholder can have a format after a colon, like `{name:[[fill]align][+][0][width][.precision][type]}`, where align is one of `<`, `^` or `>`, and type is one of `x`, `X`, `o`, `b` (for integers) or `e` (for numbers).",
+    )
+    .add_description_line("Use `{{` and `}}` for literal braces.")
+    .add_description_line(
+        "If a value is missing or can not be formatted, or if the template is invalid, nothing will be returned.",
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"{name} is {age:>5} years, balance {balance:.2}\"")
+            .add_argument(".")
+            .input("{\"name\": \"Alice\", \"age\": 31, \"balance\": 1204.5}")
+            .expected_output("\"Alice is    31 years, balance 1204.50\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"{} + {} = {2:03}\"")
+            .add_argument("1")
+            .add_argument("2")
+            .add_argument("3")
+            .expected_output("\"1 + 2 = 003\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"[{0:-^9}] {0:#>4x}\"")
+            .add_argument("255")
+            .expected_output("\"[---255---] ##ff\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"{user.id}: {user.tags}\"")
+            .add_argument("{\"user\": {\"id\": 7, \"tags\": [\"a\"]}}")
+            .expected_output("\"7: [\\\"a\\\"]\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"{missing}\"")
+            .add_argument("{}"),
+    )
+    .add_example(Example::new().add_argument("\"{unclosed\"").add_argument("{}"))
+}
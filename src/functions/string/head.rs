@@ -36,6 +36,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["number"])
         .add_description_line("Extract a string header.")
         .add_description_line(
             "If the first argument is a string and the second argument is a positive integer, the returned value will be a string with the beginning of the first argument."
@@ -1,30 +1,39 @@
 mod base63_decode;
 mod concat;
 mod env;
+mod format;
 mod head;
 mod parse_and_stringify;
 mod regex;
 mod split;
 mod tail;
+mod transform;
+mod unicode;
 
 use crate::functions_definitions::FunctionsGroup;
 use base63_decode::get as get_base63_decode;
 use concat::get as get_concat;
 use env::get as get_env;
+use format::get as get_format;
 use head::get as get_head;
 use parse_and_stringify::group as parse;
 use regex::group as get_regex;
 use split::get as get_split;
 use tail::get as get_tail;
+use transform::group as get_transform;
+use unicode::group as get_unicode;
 
 pub fn group() -> FunctionsGroup {
     FunctionsGroup::new("string")
         .add_function(get_env())
         .add_function(get_concat())
+        .add_function(get_format())
         .add_function(get_head())
         .add_function(get_tail())
         .add_function(get_split())
         .add_function(get_base63_decode())
         .add_sub_group(parse())
         .add_sub_group(get_regex())
+        .add_sub_group(get_transform())
+        .add_sub_group(get_unicode())
 }
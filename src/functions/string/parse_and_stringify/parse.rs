@@ -32,6 +32,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
     .add_alias("parse_json")
     .add_description_line("Parse a string into JSON value.")
     .add_example(
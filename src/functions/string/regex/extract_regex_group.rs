@@ -49,6 +49,9 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
+    .add_argument_types(&["number"])
         .add_description_line("Return the capture group within the string.")
         .add_description_line(
             "The first argument is expected to be the string to apply the expression on."
@@ -29,6 +29,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
     .add_alias("match_regex")
     .add_description_line(
         "Return true if the first string argument match the second regular expression argument.",
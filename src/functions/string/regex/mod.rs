@@ -1,13 +1,25 @@
 mod extract_regex_group;
 mod match_regex;
+mod regex_captures;
+mod regex_find_all;
+mod regex_replace;
+mod regex_split;
 
 use crate::functions_definitions::FunctionsGroup;
 use extract_regex_group::get as get_extract_regex_group;
 use match_regex::get as get_match_regex;
+use regex_captures::get as get_regex_captures;
+use regex_find_all::get as get_regex_find_all;
+use regex_replace::get as get_regex_replace;
+use regex_split::get as get_regex_split;
 
 pub fn group() -> FunctionsGroup {
     FunctionsGroup::new("regex")
         .add_function(get_match_regex())
         .add_function(get_extract_regex_group())
+        .add_function(get_regex_replace())
+        .add_function(get_regex_split())
+        .add_function(get_regex_find_all())
+        .add_function(get_regex_captures())
         .add_description_line("Regular expression functions")
 }
@@ -0,0 +1,79 @@
+use indexmap::IndexMap;
+use std::rc::Rc;
+
+use crate::processor::Context;
+use crate::regex_cache::RegexCompile;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("regex_captures", 2, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                if let (Some(JsonValue::String(str)), Some(JsonValue::String(regex))) =
+                    (self.0.apply(value, 0), self.0.apply(value, 1))
+                {
+                    if let Ok(regex) = &*value.compile_regex(&regex) {
+                        let names: Vec<_> = regex.capture_names().flatten().collect();
+                        let matches = regex
+                            .captures_iter(&str)
+                            .map(|captures| {
+                                let mut groups = IndexMap::with_capacity(names.len());
+                                for name in &names {
+                                    let group = match captures.name(name) {
+                                        Some(group) => group.as_str().to_string().into(),
+                                        None => JsonValue::Null,
+                                    };
+                                    groups.insert(name.to_string(), group);
+                                }
+                                groups.into()
+                            })
+                            .collect::<Vec<JsonValue>>();
+                        Some(matches.into())
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
+    .add_description_line(
+        "Return the named capture groups of each match of the second regular expression argument within the first string argument.",
+    )
+    .add_description_line(
+        "Each match is an object from the group name to the captured string (or null, if the group did not participate in the match).",
+    )
+    .add_description_line(
+        "For regular expression syntax, see [https://docs.rs/regex/latest/regex/#syntax].",
+    )
+    .add_description_line(
+        "Use `--regular_expression_cache_size` so set a cache for compiled regular expressions.",
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"a=1, b=2\"")
+            .add_argument("\"(?<key>[a-z]+)=(?<value>[0-9]+)\"")
+            .expected_output("[{\"key\": \"a\", \"value\": \"1\"}, {\"key\": \"b\", \"value\": \"2\"}]"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"v1 v2-beta\"")
+            .add_argument("\"v(?<major>[0-9]+)(-(?<tag>[a-z]+))?\"")
+            .expected_output("[{\"major\": \"1\", \"tag\": null}, {\"major\": \"2\", \"tag\": \"beta\"}]"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"test\"")
+            .add_argument("\"[0-9\""),
+    )
+}
@@ -0,0 +1,85 @@
+use indexmap::IndexMap;
+use std::rc::Rc;
+
+use crate::processor::Context;
+use crate::regex_cache::RegexCompile;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("regex_find_all", 2, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                if let (Some(JsonValue::String(str)), Some(JsonValue::String(regex))) =
+                    (self.0.apply(value, 0), self.0.apply(value, 1))
+                {
+                    if let Ok(regex) = &*value.compile_regex(&regex) {
+                        let mut matches = vec![];
+                        let mut byte_offset = 0;
+                        let mut char_offset = 0;
+                        for found in regex.find_iter(&str) {
+                            char_offset += str[byte_offset..found.start()].chars().count();
+                            let start = char_offset;
+                            let end = start + found.as_str().chars().count();
+                            byte_offset = found.end();
+                            char_offset = end;
+                            let mut details = IndexMap::with_capacity(3);
+                            details.insert("match".to_string(), found.as_str().to_string().into());
+                            details.insert("start".to_string(), start.into());
+                            details.insert("end".to_string(), end.into());
+                            matches.push(details.into());
+                        }
+                        Some(matches.into())
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
+    .add_description_line(
+        "Return all the matches of the second regular expression argument within the first string argument.",
+    )
+    .add_description_line(
+        "Each match is an object with the matched `match` string, and its `start` and `end` positions (in characters, the end is exclusive).",
+    )
+    .add_description_line(
+        "For regular expression syntax, see [https://docs.rs/regex/latest/regex/#syntax].",
+    )
+    .add_description_line(
+        "Use `--regular_expression_cache_size` so set a cache for compiled regular expressions.",
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"a1b22c333\"")
+            .add_argument("\"[0-9]+\"")
+            .expected_output("[{\"match\": \"1\", \"start\": 1, \"end\": 2}, {\"match\": \"22\", \"start\": 3, \"end\": 5}, {\"match\": \"333\", \"start\": 6, \"end\": 9}]"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"ö-ö\"")
+            .add_argument("\"-\"")
+            .expected_output("[{\"match\": \"-\", \"start\": 1, \"end\": 2}]"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"test\"")
+            .add_argument("\"[0-9]+\"")
+            .expected_output("[]"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"test\"")
+            .add_argument("\"[0-9\""),
+    )
+}
@@ -0,0 +1,104 @@
+use std::rc::Rc;
+
+use crate::processor::Context;
+use crate::regex_cache::RegexCompile;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("regex_replace", 3, 4, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let (
+                    Some(JsonValue::String(str)),
+                    Some(JsonValue::String(regex)),
+                    Some(JsonValue::String(replacement)),
+                ) = (
+                    self.0.apply(value, 0),
+                    self.0.apply(value, 1),
+                    self.0.apply(value, 2),
+                )
+                else {
+                    return None;
+                };
+                let all = if self.0.len() > 3 {
+                    let Some(JsonValue::Boolean(all)) = self.0.apply(value, 3) else {
+                        return None;
+                    };
+                    all
+                } else {
+                    false
+                };
+                let Ok(regex) = &*value.compile_regex(&regex) else {
+                    return None;
+                };
+                let replaced = if all {
+                    regex.replace_all(&str, replacement.as_str())
+                } else {
+                    regex.replace(&str, replacement.as_str())
+                };
+                Some(replaced.to_string().into())
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
+    .add_argument_types(&["boolean"])
+    .add_description_line("Replace the first match of a regular expression within the string.")
+    .add_description_line(
+        "The first argument is the string, the second is the regular expression and the third is the replacement.",
+    )
+    .add_description_line(
+        "The replacement can refer to the capture groups, by index (like `$1`) or by name (like `${name}`). Use `$$` for a literal `$`.",
+    )
+    .add_description_line("If the optional fourth argument is `true`, all the matches will be replaced.")
+    .add_description_line(
+        "For regular expression syntax, see [https://docs.rs/regex/latest/regex/#syntax].",
+    )
+    .add_description_line(
+        "Use `--regular_expression_cache_size` so set a cache for compiled regular expressions.",
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"2024-03-15\"")
+            .add_argument("\"([0-9]+)-([0-9]+)-([0-9]+)\"")
+            .add_argument("\"$3/$2/$1\"")
+            .expected_output("\"15/03/2024\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"a1b22c333\"")
+            .add_argument("\"[0-9]+\"")
+            .add_argument("\"#\"")
+            .expected_output("\"a#b22c333\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"a1b22c333\"")
+            .add_argument("\"[0-9]+\"")
+            .add_argument("\"#\"")
+            .add_argument("true")
+            .expected_output("\"a#b#c#\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"John Smith\"")
+            .add_argument("\"(?<first>[a-zA-Z]+) (?<last>[a-zA-Z]+)\"")
+            .add_argument("\"${last}, ${first}\"")
+            .add_argument("true")
+            .expected_output("\"Smith, John\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"test\"")
+            .add_argument("\"[0-9\"")
+            .add_argument("\"x\""),
+    )
+}
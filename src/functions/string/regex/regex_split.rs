@@ -0,0 +1,67 @@
+use std::rc::Rc;
+
+use crate::processor::Context;
+use crate::regex_cache::RegexCompile;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("regex_split", 2, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                if let (Some(JsonValue::String(str)), Some(JsonValue::String(regex))) =
+                    (self.0.apply(value, 0), self.0.apply(value, 1))
+                {
+                    if let Ok(regex) = &*value.compile_regex(&regex) {
+                        Some(
+                            regex
+                                .split(&str)
+                                .map(|part| JsonValue::String(part.to_string()))
+                                .collect::<Vec<_>>()
+                                .into(),
+                        )
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
+    .add_description_line(
+        "Split the first string argument into array of strings, by the matches of the second regular expression argument.",
+    )
+    .add_description_line("See also [`split`](split.md).")
+    .add_description_line(
+        "For regular expression syntax, see [https://docs.rs/regex/latest/regex/#syntax].",
+    )
+    .add_description_line(
+        "Use `--regular_expression_cache_size` so set a cache for compiled regular expressions.",
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"one, two;three\"")
+            .add_argument("\"[,;] *\"")
+            .expected_output("[\"one\", \"two\", \"three\"]"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"a1b22c\"")
+            .add_argument("\"[0-9]+\"")
+            .expected_output("[\"a\", \"b\", \"c\"]"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"test\"")
+            .add_argument("\"[0-9\""),
+    )
+}
@@ -29,6 +29,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
     .add_description_line("Split the string into array of strings.")
     .add_example(
         Example::new()
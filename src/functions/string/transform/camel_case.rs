@@ -0,0 +1,57 @@
+use std::rc::Rc;
+
+use super::words::words;
+use crate::processor::Context;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("camel_case", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                if let Some(JsonValue::String(str)) = self.0.apply(value, 0) {
+                    Some(camel_case(&str).into())
+                } else {
+                    None
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_description_line("Convert the string to `camelCase`.")
+    .add_description_line("Words are separated by anything that is not a letter or a digit, and by a change from lower case to upper case.")
+    .add_example(
+        Example::new()
+            .add_argument("\"hello_world\"")
+            .expected_output("\"helloWorld\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"Content-Type header\"")
+            .expected_output("\"contentTypeHeader\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[]"),
+    )
+}
+
+fn camel_case(str: &str) -> String {
+    let mut camel = String::with_capacity(str.len());
+    for (index, word) in words(str).iter().enumerate() {
+        let mut chars = word.chars();
+        if index > 0
+            && let Some(first) = chars.next()
+        {
+            camel.extend(first.to_uppercase());
+        }
+        camel.push_str(chars.as_str());
+    }
+    camel
+}
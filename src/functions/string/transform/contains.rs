@@ -0,0 +1,47 @@
+use std::rc::Rc;
+
+use crate::processor::Context;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("contains", 2, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                if let (Some(JsonValue::String(str)), Some(JsonValue::String(part))) =
+                    (self.0.apply(value, 0), self.0.apply(value, 1))
+                {
+                    Some(str.contains(part.as_str()).into())
+                } else {
+                    None
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
+    .add_description_line("Check if the first string contains the second string.")
+    .add_example(
+        Example::new()
+            .add_argument("\"hello world\"")
+            .add_argument("\"o w\"")
+            .expected_output("true"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"hello world\"")
+            .add_argument("\"World\"")
+            .expected_output("false"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("[\"hello\"]")
+            .add_argument("\"hello\""),
+    )
+}
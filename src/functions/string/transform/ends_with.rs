@@ -0,0 +1,47 @@
+use std::rc::Rc;
+
+use crate::processor::Context;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("ends_with", 2, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                if let (Some(JsonValue::String(str)), Some(JsonValue::String(suffix))) =
+                    (self.0.apply(value, 0), self.0.apply(value, 1))
+                {
+                    Some(str.ends_with(suffix.as_str()).into())
+                } else {
+                    None
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
+    .add_description_line("Check if the first string ends with the second string.")
+    .add_example(
+        Example::new()
+            .add_argument("\"report.json\"")
+            .add_argument("\".json\"")
+            .expected_output("true"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"report.csv\"")
+            .add_argument("\".json\"")
+            .expected_output("false"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"report.json\"")
+            .add_argument("null"),
+    )
+}
@@ -0,0 +1,51 @@
+use std::rc::Rc;
+
+use crate::processor::Context;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("index_of", 2, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                if let (Some(JsonValue::String(str)), Some(JsonValue::String(part))) =
+                    (self.0.apply(value, 0), self.0.apply(value, 1))
+                {
+                    str.find(part.as_str())
+                        .map(|index| str[..index].chars().count().into())
+                } else {
+                    None
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
+    .add_description_line("Find the first position of the second string within the first string.")
+    .add_description_line(
+        "The position is in characters, and the first character is in position zero. If the second string can not be found, nothing will be returned.",
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"hello world\"")
+            .add_argument("\"o\"")
+            .expected_output("4"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"héllo wörld\"")
+            .add_argument("\"wö\"")
+            .expected_output("6"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"hello world\"")
+            .add_argument("\"x\""),
+    )
+}
@@ -0,0 +1,33 @@
+use std::rc::Rc;
+
+use crate::processor::Context;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("lower", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                if let Some(JsonValue::String(str)) = self.0.apply(value, 0) {
+                    Some(str.to_lowercase().into())
+                } else {
+                    None
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_description_line("Convert the string to lower case.")
+    .add_example(
+        Example::new()
+            .add_argument("\"Hello World\"")
+            .expected_output("\"hello world\""),
+    )
+    .add_example(Example::new().add_argument("null"))
+}
@@ -0,0 +1,68 @@
+mod camel_case;
+mod contains;
+mod ends_with;
+mod index_of;
+mod kebab_case;
+mod lower;
+mod pad_left;
+mod pad_right;
+mod repeat;
+mod replace_all;
+mod replace_first;
+mod reverse_string;
+mod snake_case;
+mod starts_with;
+mod title_case;
+mod trim;
+mod trim_end;
+mod trim_start;
+mod upper;
+mod words;
+
+use crate::functions_definitions::FunctionsGroup;
+use camel_case::get as get_camel_case;
+use contains::get as get_contains;
+use ends_with::get as get_ends_with;
+use index_of::get as get_index_of;
+use kebab_case::get as get_kebab_case;
+use lower::get as get_lower;
+use pad_left::get as get_pad_left;
+use pad_right::get as get_pad_right;
+use repeat::get as get_repeat;
+use replace_all::get as get_replace_all;
+use replace_first::get as get_replace_first;
+use reverse_string::get as get_reverse_string;
+use snake_case::get as get_snake_case;
+use starts_with::get as get_starts_with;
+use title_case::get as get_title_case;
+use trim::get as get_trim;
+use trim_end::get as get_trim_end;
+use trim_start::get as get_trim_start;
+use upper::get as get_upper;
+
+/// The longest string (in bytes for `repeat`, in characters for the padding) that the functions will build.
+const MAX_LENGTH: usize = 100_000_000;
+
+pub fn group() -> FunctionsGroup {
+    FunctionsGroup::new("transform")
+        .add_function(get_upper())
+        .add_function(get_lower())
+        .add_function(get_title_case())
+        .add_function(get_snake_case())
+        .add_function(get_camel_case())
+        .add_function(get_kebab_case())
+        .add_function(get_trim())
+        .add_function(get_trim_start())
+        .add_function(get_trim_end())
+        .add_function(get_pad_left())
+        .add_function(get_pad_right())
+        .add_function(get_replace_all())
+        .add_function(get_replace_first())
+        .add_function(get_repeat())
+        .add_function(get_starts_with())
+        .add_function(get_ends_with())
+        .add_function(get_contains())
+        .add_function(get_index_of())
+        .add_function(get_reverse_string())
+        .add_description_line("Functions to transform, pad and search text")
+}
@@ -0,0 +1,93 @@
+use std::rc::Rc;
+
+use super::MAX_LENGTH;
+use crate::processor::Context;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("pad_left", 2, 3, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let (Some(JsonValue::String(str)), Some(JsonValue::Number(length))) =
+                    (self.0.apply(value, 0), self.0.apply(value, 1))
+                else {
+                    return None;
+                };
+                let length = TryInto::<usize>::try_into(length).ok()?;
+                let padding = if self.0.len() > 2 {
+                    let Some(JsonValue::String(padding)) = self.0.apply(value, 2) else {
+                        return None;
+                    };
+                    padding
+                } else {
+                    " ".to_string()
+                };
+                let padding = padding_for(&str, length, &padding)?;
+                Some(format!("{padding}{str}").into())
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["number"])
+    .add_argument_types(&["string"])
+    .add_description_line("Add characters to the beginning of the string until it reaches the requested length.")
+    .add_description_line(
+        "The first argument is the string, the second is the length (in characters) and the optional third is the string to pad with (a space by default).",
+    )
+    .add_description_line("If the string is already long enough, it will be returned as is.")
+    .add_description_line("If the length is more than 100 million, nothing will be returned.")
+    .add_example(
+        Example::new()
+            .add_argument("\"42\"")
+            .add_argument("5")
+            .add_argument("\"0\"")
+            .expected_output("\"00042\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"abc\"")
+            .add_argument("6")
+            .expected_output("\"   abc\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"abcdef\"")
+            .add_argument("3")
+            .expected_output("\"abcdef\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"abc\"")
+            .add_argument("5")
+            .add_argument("\"\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"a\"")
+            .add_argument("9223372036854775807")
+            .add_argument("\" \""),
+    )
+}
+
+/// The padding to add to a string so it will have `length` characters, or nothing if the padding is empty or the
+/// length is too large.
+pub fn padding_for(str: &str, length: usize, padding: &str) -> Option<String> {
+    if length > MAX_LENGTH {
+        return None;
+    }
+    let missing = length.saturating_sub(str.chars().count());
+    if missing == 0 {
+        return Some(String::new());
+    }
+    if padding.is_empty() {
+        return None;
+    }
+    Some(padding.chars().cycle().take(missing).collect())
+}
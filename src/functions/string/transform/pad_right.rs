@@ -0,0 +1,61 @@
+use std::rc::Rc;
+
+use super::pad_left::padding_for;
+use crate::processor::Context;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("pad_right", 2, 3, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let (Some(JsonValue::String(str)), Some(JsonValue::Number(length))) =
+                    (self.0.apply(value, 0), self.0.apply(value, 1))
+                else {
+                    return None;
+                };
+                let length = TryInto::<usize>::try_into(length).ok()?;
+                let padding = if self.0.len() > 2 {
+                    let Some(JsonValue::String(padding)) = self.0.apply(value, 2) else {
+                        return None;
+                    };
+                    padding
+                } else {
+                    " ".to_string()
+                };
+                let padding = padding_for(&str, length, &padding)?;
+                Some(format!("{str}{padding}").into())
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["number"])
+    .add_argument_types(&["string"])
+    .add_description_line("Add characters to the end of the string until it reaches the requested length.")
+    .add_description_line(
+        "The first argument is the string, the second is the length (in characters) and the optional third is the string to pad with (a space by default).",
+    )
+    .add_description_line("If the string is already long enough, it will be returned as is.")
+    .add_description_line("If the length is more than 100 million, nothing will be returned.")
+    .add_example(
+        Example::new()
+            .add_argument("\"name\"")
+            .add_argument("8")
+            .add_argument("\".\"")
+            .expected_output("\"name....\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"ab\"")
+            .add_argument("7")
+            .add_argument("\"-=\"")
+            .expected_output("\"ab-=-=-\""),
+    )
+    .add_example(Example::new().add_argument("\"ab\"").add_argument("-1"))
+}
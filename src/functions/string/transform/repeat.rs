@@ -0,0 +1,57 @@
+use std::rc::Rc;
+
+use super::MAX_LENGTH;
+use crate::processor::Context;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("repeat", 2, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                if let (Some(JsonValue::String(str)), Some(JsonValue::Number(count))) =
+                    (self.0.apply(value, 0), self.0.apply(value, 1))
+                {
+                    let count = TryInto::<usize>::try_into(count).ok()?;
+                    if str.len().checked_mul(count)? > MAX_LENGTH {
+                        return None;
+                    }
+                    Some(str.repeat(count).into())
+                } else {
+                    None
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["number"])
+    .add_description_line("Repeat the string a number of times.")
+    .add_description_line("The second argument should be a non negative integer.")
+    .add_description_line(
+        "If the result would be longer than 100 million bytes, nothing will be returned.",
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"ab\"")
+            .add_argument("3")
+            .expected_output("\"ababab\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"ab\"")
+            .add_argument("0")
+            .expected_output("\"\""),
+    )
+    .add_example(Example::new().add_argument("\"ab\"").add_argument("1.5"))
+    .add_example(
+        Example::new()
+            .add_argument("\"ab\"")
+            .add_argument("9223372036854775807"),
+    )
+}
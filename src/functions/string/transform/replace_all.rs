@@ -0,0 +1,62 @@
+use std::rc::Rc;
+
+use crate::processor::Context;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("replace_all", 3, 3, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                if let (
+                    Some(JsonValue::String(str)),
+                    Some(JsonValue::String(from)),
+                    Some(JsonValue::String(to)),
+                ) = (
+                    self.0.apply(value, 0),
+                    self.0.apply(value, 1),
+                    self.0.apply(value, 2),
+                ) {
+                    Some(str.replace(from.as_str(), &to).into())
+                } else {
+                    None
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
+    .add_description_line("Replace all the occurrences of a text within the string.")
+    .add_description_line(
+        "The first argument is the string, the second is the text to look for and the third is the text to put instead of it.",
+    )
+    .add_description_line("The text to look for is literal, not a regular expression.")
+    .add_description_line("See also [`replace_first`](replace_first.md).")
+    .add_example(
+        Example::new()
+            .add_argument("\"a.b.c\"")
+            .add_argument("\".\"")
+            .add_argument("\"/\"")
+            .expected_output("\"a/b/c\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"a.b.c\"")
+            .add_argument("\"x\"")
+            .add_argument("\"y\"")
+            .expected_output("\"a.b.c\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"a.b.c\"")
+            .add_argument("\".\"")
+            .add_argument("1"),
+    )
+}
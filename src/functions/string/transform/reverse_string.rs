@@ -0,0 +1,35 @@
+use std::rc::Rc;
+
+use crate::processor::Context;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("reverse_string", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                if let Some(JsonValue::String(str)) = self.0.apply(value, 0) {
+                    Some(str.chars().rev().collect::<String>().into())
+                } else {
+                    None
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_description_line("Reverse the characters of the string.")
+    .add_description_line("See also [`reverse`](reverse.md) for lists.")
+    .add_example(
+        Example::new()
+            .add_argument("\"hello\"")
+            .expected_output("\"olleh\""),
+    )
+    .add_example(Example::new().add_argument("\"\"").expected_output("\"\""))
+    .add_example(Example::new().add_argument("[1, 2]"))
+}
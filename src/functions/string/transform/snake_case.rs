@@ -0,0 +1,43 @@
+use std::rc::Rc;
+
+use super::words::words;
+use crate::processor::Context;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("snake_case", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                if let Some(JsonValue::String(str)) = self.0.apply(value, 0) {
+                    Some(words(&str).join("_").into())
+                } else {
+                    None
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_description_line("Convert the string to `snake_case`.")
+    .add_description_line("Words are separated by anything that is not a letter or a digit, and by a change from lower case to upper case.")
+    .add_example(
+        Example::new()
+            .add_argument("\"helloWorld\"")
+            .expected_output("\"hello_world\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"HTTP server-Name\"")
+            .expected_output("\"http_server_name\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("1"),
+    )
+}
@@ -0,0 +1,58 @@
+use std::rc::Rc;
+
+use crate::processor::Context;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("title_case", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                if let Some(JsonValue::String(str)) = self.0.apply(value, 0) {
+                    Some(title_case(&str).into())
+                } else {
+                    None
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_description_line("Capitalize the first letter of each word in the string, and lower case the rest of the letters.")
+    .add_example(
+        Example::new()
+            .add_argument("\"hello wORLD\"")
+            .expected_output("\"Hello World\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"  one-two three\"")
+            .expected_output("\"  One-two Three\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("true"),
+    )
+}
+
+fn title_case(str: &str) -> String {
+    let mut title = String::with_capacity(str.len());
+    let mut start_of_word = true;
+    for ch in str.chars() {
+        if ch.is_whitespace() {
+            start_of_word = true;
+            title.push(ch);
+        } else if start_of_word {
+            start_of_word = false;
+            title.extend(ch.to_uppercase());
+        } else {
+            title.extend(ch.to_lowercase());
+        }
+    }
+    title
+}
@@ -0,0 +1,52 @@
+use std::rc::Rc;
+
+use crate::processor::Context;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("trim_start", 1, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::String(str)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                if self.0.len() == 1 {
+                    return Some(str.trim_start().to_string().into());
+                }
+                let Some(JsonValue::String(characters)) = self.0.apply(value, 1) else {
+                    return None;
+                };
+                Some(
+                    str.trim_start_matches(|ch| characters.contains(ch))
+                        .to_string()
+                        .into(),
+                )
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
+    .add_description_line("Remove the white spaces from the beginning of the string.")
+    .add_description_line(
+        "If a second argument is supplied, it should be a string with the characters to remove (instead of the white spaces).",
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"  hello \"")
+            .expected_output("\"hello \""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"000120\"")
+            .add_argument("\"0\"")
+            .expected_output("\"120\""),
+    )
+    .add_example(Example::new().add_argument("\" a \"").add_argument("1"))
+}
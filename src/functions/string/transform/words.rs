@@ -0,0 +1,47 @@
+/// Split a string into lower case words, for the case conversion functions.
+///
+/// Words are separated by anything that is not a letter or a digit, by a lower case letter (or a digit) that is
+/// followed by an upper case letter, and by the last upper case letter of an acronym that is followed by a lower
+/// case letter (so `HTTPServer` is `http` and `server`).
+pub fn words(str: &str) -> Vec<String> {
+    let chars: Vec<char> = str.chars().collect();
+    let mut words = vec![];
+    let mut word = String::new();
+    for (index, ch) in chars.iter().enumerate() {
+        if !ch.is_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        if ch.is_uppercase()
+            && let Some(previous) = index.checked_sub(1).map(|index| chars[index])
+        {
+            let next_is_lower = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+            let starts_word = previous.is_lowercase()
+                || previous.is_numeric()
+                || (previous.is_uppercase() && next_is_lower);
+            if starts_word && !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        word.extend(ch.to_lowercase());
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words_split_by_separators_and_case() {
+        assert_eq!(words("helloWorld"), vec!["hello", "world"]);
+        assert_eq!(words("HTTPServer_name"), vec!["http", "server", "name"]);
+        assert_eq!(words("  version2Beta -- x"), vec!["version2", "beta", "x"]);
+        assert!(words("--").is_empty());
+    }
+}
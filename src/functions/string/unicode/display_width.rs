@@ -0,0 +1,49 @@
+use unicode_width::UnicodeWidthStr;
+
+use std::rc::Rc;
+
+use crate::processor::Context;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("display_width", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                if let Some(JsonValue::String(str)) = self.0.apply(value, 0) {
+                    Some(str.width().into())
+                } else {
+                    None
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_description_line("Return the number of columns the string takes when displayed in a terminal.")
+    .add_description_line(
+        "East Asian wide characters and most emoji take two columns, and combining characters take none.",
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"hello\"")
+            .expected_output("5"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"\\u65e5\\u672c\"")
+            .expected_output("4")
+            .explain("each of the two Japanese characters is two columns wide."),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"e\\u0301\"")
+            .expected_output("1"),
+    )
+    .add_example(Example::new().add_argument("true"))
+}
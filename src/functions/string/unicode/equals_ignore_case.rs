@@ -0,0 +1,55 @@
+use caseless::default_caseless_match_str;
+
+use std::rc::Rc;
+
+use crate::processor::Context;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("equals_ignore_case", 2, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                if let (Some(JsonValue::String(one)), Some(JsonValue::String(two))) =
+                    (self.0.apply(value, 0), self.0.apply(value, 1))
+                {
+                    Some(default_caseless_match_str(&one, &two).into())
+                } else {
+                    None
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
+    .add_alias("caseless_eq")
+    .add_description_line("Compare two strings, ignoring the case.")
+    .add_description_line(
+        "The strings are compared using unicode case folding, so letters like `ß` and `ss` are considered equal.",
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"Hello\"")
+            .add_argument("\"hELLO\"")
+            .expected_output("true"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"Stra\\u00dfe\"")
+            .add_argument("\"STRASSE\"")
+            .expected_output("true"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"Hello\"")
+            .add_argument("\"World\"")
+            .expected_output("false"),
+    )
+    .add_example(Example::new().add_argument("\"1\"").add_argument("1"))
+}
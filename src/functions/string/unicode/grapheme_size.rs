@@ -0,0 +1,51 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use std::rc::Rc;
+
+use crate::processor::Context;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("grapheme_size", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                if let Some(JsonValue::String(str)) = self.0.apply(value, 0) {
+                    Some(str.graphemes(true).count().into())
+                } else {
+                    None
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_alias("grapheme_length")
+    .add_description_line(
+        "Return the number of user visible characters (extended grapheme clusters) in the string.",
+    )
+    .add_description_line("See also [`size`](size.md).")
+    .add_example(
+        Example::new()
+            .add_argument("\"hello\"")
+            .expected_output("5"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"e\\u0301\"")
+            .expected_output("1")
+            .explain("the combining accent is part of the `e`."),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"🇮🇱\"")
+            .expected_output("1")
+            .explain("the two regional indicators are a single flag."),
+    )
+    .add_example(Example::new().add_argument("12"))
+}
@@ -0,0 +1,54 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use std::rc::Rc;
+
+use crate::processor::Context;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("grapheme_tail", 2, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                if let (Some(JsonValue::String(str)), Some(JsonValue::Number(index))) =
+                    (self.0.apply(value, 0), self.0.apply(value, 1))
+                {
+                    let index = TryInto::<usize>::try_into(index).ok()?;
+                    let split_at = str
+                        .grapheme_indices(true)
+                        .nth(index)
+                        .map_or(str.len(), |(split_at, _)| split_at);
+                    Some(str[split_at..].to_string().into())
+                } else {
+                    None
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["number"])
+    .add_description_line("Extract a string tail, counted in user visible characters (extended grapheme clusters).")
+    .add_description_line(
+        "If the first argument is a string and the second argument is a positive integer, the returned value will be a string without that many graphemes from the beginning of the first argument.",
+    )
+    .add_description_line("See also [`tail`](tail.md).")
+    .add_example(
+        Example::new()
+            .add_argument("\"e\\u0301te\\u0301\"")
+            .add_argument("1")
+            .expected_output("\"te\\u0301\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"test\"")
+            .add_argument("20")
+            .expected_output("\"\""),
+    )
+    .add_example(Example::new().add_argument("20").add_argument("1"))
+}
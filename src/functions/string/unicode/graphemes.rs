@@ -0,0 +1,48 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use std::rc::Rc;
+
+use crate::processor::Context;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("graphemes", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                if let Some(JsonValue::String(str)) = self.0.apply(value, 0) {
+                    Some(
+                        str.graphemes(true)
+                            .map(|grapheme| JsonValue::String(grapheme.to_string()))
+                            .collect::<Vec<_>>()
+                            .into(),
+                    )
+                } else {
+                    None
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_description_line("Split the string into an array of user visible characters (extended grapheme clusters).")
+    .add_description_line(
+        "Unlike characters, a grapheme keeps combining characters and emoji sequences (like flags or family emoji) together.",
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"abc\"")
+            .expected_output("[\"a\", \"b\", \"c\"]"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"e\\u0301!\"")
+            .expected_output("[\"e\\u0301\", \"!\"]"),
+    )
+    .add_example(Example::new().add_argument("[\"abc\"]"))
+}
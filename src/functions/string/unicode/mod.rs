@@ -0,0 +1,31 @@
+mod display_width;
+mod equals_ignore_case;
+mod grapheme_head;
+mod grapheme_size;
+mod grapheme_tail;
+mod graphemes;
+mod normalize;
+mod remove_accents;
+
+use crate::functions_definitions::FunctionsGroup;
+use display_width::get as get_display_width;
+use equals_ignore_case::get as get_equals_ignore_case;
+use grapheme_head::get as get_grapheme_head;
+use grapheme_size::get as get_grapheme_size;
+use grapheme_tail::get as get_grapheme_tail;
+use graphemes::get as get_graphemes;
+use normalize::get as get_normalize;
+use remove_accents::get as get_remove_accents;
+
+pub fn group() -> FunctionsGroup {
+    FunctionsGroup::new("unicode")
+        .add_function(get_normalize())
+        .add_function(get_graphemes())
+        .add_function(get_grapheme_size())
+        .add_function(get_grapheme_head())
+        .add_function(get_grapheme_tail())
+        .add_function(get_display_width())
+        .add_function(get_remove_accents())
+        .add_function(get_equals_ignore_case())
+        .add_description_line("Unicode aware text functions")
+}
@@ -0,0 +1,74 @@
+use unicode_normalization::UnicodeNormalization;
+
+use std::rc::Rc;
+
+use crate::processor::Context;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("normalize", 1, 2, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let Some(JsonValue::String(str)) = self.0.apply(value, 0) else {
+                    return None;
+                };
+                let form = if self.0.len() > 1 {
+                    let Some(JsonValue::String(form)) = self.0.apply(value, 1) else {
+                        return None;
+                    };
+                    form.to_lowercase()
+                } else {
+                    "nfc".to_string()
+                };
+                let normalized: String = match form.as_str() {
+                    "nfc" => str.nfc().collect(),
+                    "nfd" => str.nfd().collect(),
+                    "nfkc" => str.nfkc().collect(),
+                    "nfkd" => str.nfkd().collect(),
+                    _ => return None,
+                };
+                Some(normalized.into())
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
+    .add_description_line("Normalize the unicode representation of a string.")
+    .add_description_line(
+        "The optional second argument is the normalization form, one of `\"NFC\"` (the default), `\"NFD\"`, `\"NFKC\"` or `\"NFKD\"`.",
+    )
+    .add_description_line(
+        "For the normalization forms, see [https://unicode.org/reports/tr15/].",
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"e\\u0301\"")
+            .expected_output("\"\\u00e9\"")
+            .explain("an `e` followed by a combining acute accent is composed into a single `é`."),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"\\u00e9\"")
+            .add_argument("\"NFD\"")
+            .expected_output("\"e\\u0301\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"\\ufb01le\"")
+            .add_argument("\"NFKC\"")
+            .expected_output("\"file\"")
+            .explain("the `ﬁ` ligature is compatible with the letters `f` and `i`."),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"text\"")
+            .add_argument("\"NFX\""),
+    )
+}
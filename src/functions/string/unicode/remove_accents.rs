@@ -0,0 +1,49 @@
+use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
+
+use std::rc::Rc;
+
+use crate::processor::Context;
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_value::JsonValue,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("remove_accents", 1, 1, |args| {
+        struct Impl(Vec<Rc<dyn Get>>);
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                if let Some(JsonValue::String(str)) = self.0.apply(value, 0) {
+                    Some(
+                        str.nfd()
+                            .filter(|ch| !is_combining_mark(*ch))
+                            .nfc()
+                            .collect::<String>()
+                            .into(),
+                    )
+                } else {
+                    None
+                }
+            }
+        }
+        Rc::new(Impl(args))
+    })
+    .add_argument_types(&["string"])
+    .add_description_line("Remove the accents and other combining marks from the string.")
+    .add_description_line(
+        "The string is decomposed (see [`normalize`](normalize.md)), so accented letters lose their accents as well.",
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"Cr\\u00e8me Br\\u00fbl\\u00e9e\"")
+            .expected_output("\"Creme Brulee\""),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("\"e\\u0301\"")
+            .expected_output("\"e\""),
+    )
+    .add_example(Example::new().add_argument("null"))
+}
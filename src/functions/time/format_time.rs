@@ -34,6 +34,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["number"])
+    .add_argument_types(&["string"])
     .add_description_line("Format a date/time into a string")
     .add_description_line("The first argument should be the number of seconds since epoch")
     .add_description_line("The second argument should be the format as string")
@@ -31,6 +31,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
     .add_description_line("Parse a date/time from a string into seconds since epoc")
     .add_description_line("The first argument should be the date")
     .add_description_line("The second argument should be the format as string")
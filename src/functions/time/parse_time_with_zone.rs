@@ -33,6 +33,8 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
+    .add_argument_types(&["string"])
         .add_description_line(
             "Parse a date/time from a string into seconds since epoc. This version expect to get the time zone as well"
         )
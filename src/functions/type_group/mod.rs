@@ -1,12 +1,15 @@
 mod cast;
 mod check_types;
+mod validate;
 
 use crate::functions_definitions::FunctionsGroup;
 use cast::group as cast;
 use check_types::group as check_types;
+use validate::get as get_validate;
 
 pub fn group() -> FunctionsGroup {
     FunctionsGroup::new("types")
         .add_sub_group(check_types())
         .add_sub_group(cast())
+        .add_function(get_validate())
 }
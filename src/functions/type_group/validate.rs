@@ -0,0 +1,82 @@
+use std::{cell::RefCell, collections::HashMap, path::Path, rc::Rc};
+
+use crate::{
+    functions_definitions::{Arguments, Example, FunctionDefinitions},
+    json_schema::{load_schema, validate},
+    json_value::JsonValue,
+    processor::Context,
+    selection::Get,
+};
+
+pub fn get() -> FunctionDefinitions {
+    FunctionDefinitions::new("validate", 2, 2, |args| {
+        struct Impl {
+            args: Vec<Rc<dyn Get>>,
+            files: RefCell<HashMap<String, Option<Rc<JsonValue>>>>,
+        }
+        impl Impl {
+            fn schema(&self, value: &Context) -> Option<Rc<JsonValue>> {
+                match self.args.apply(value, 0)? {
+                    JsonValue::String(path) => self
+                        .files
+                        .borrow_mut()
+                        .entry(path)
+                        .or_insert_with_key(|path| load_schema(Path::new(path)).ok().map(Rc::new))
+                        .clone(),
+                    schema @ (JsonValue::Object(_) | JsonValue::Boolean(_)) => Some(Rc::new(schema)),
+                    _ => None,
+                }
+            }
+        }
+        impl Get for Impl {
+            fn get(&self, value: &Context) -> Option<JsonValue> {
+                let schema = self.schema(value)?;
+                let instance = self.args.apply(value, 1)?;
+                let violations: Vec<JsonValue> = validate(&schema, &instance, value)
+                    .iter()
+                    .map(Into::into)
+                    .collect();
+                Some(violations.into())
+            }
+        }
+        Rc::new(Impl {
+            args,
+            files: RefCell::default(),
+        })
+    })
+    .add_argument_types(&["string", "object", "boolean"])
+    .add_alias("json_schema_validate")
+    .add_description_line(
+        "Validate the second argument against the JSON schema in the first argument, and return the list of violations.",
+    )
+    .add_description_line(
+        "The schema can be a JSON schema value, or a string with the path of a file that contains it (the file will be read once).",
+    )
+    .add_description_line(
+        "Each violation is an object with the `instance_path` and the `schema_path` (as JSON pointers) and a `message`.",
+    )
+    .add_description_line(
+        "The common draft 2020-12 keywords are supported, like `type`, `properties`, `required`, `items`, `enum`, `pattern`, `minimum`, `maxLength`, `oneOf`, `anyOf`, `allOf` and `$ref` within the schema.",
+    )
+    .add_example(
+        Example::new()
+            .add_argument("{\"type\": \"number\", \"minimum\": 0}")
+            .add_argument("12")
+            .expected_output("[]"),
+    )
+    .add_example(
+        Example::new()
+            .add_argument("{\"type\": \"object\", \"required\": [\"name\"], \"properties\": {\"age\": {\"type\": \"integer\", \"minimum\": 0}}}")
+            .add_argument("{\"age\": -3}")
+            .expected_output("[{\"instance_path\": \"\", \"schema_path\": \"#/required\", \"message\": \"should have the property `name`\"}, {\"instance_path\": \"/age\", \"schema_path\": \"#/properties/age/minimum\", \"message\": \"should be at least 0\"}]"),
+    )
+    .add_example(
+        Example::new()
+            .input("{\"schema\": {\"$defs\": {\"id\": {\"type\": \"string\", \"pattern\": \"^[a-z]+$\"}}, \"items\": {\"$ref\": \"#/$defs/id\"}}, \"ids\": [\"abc\", \"A1\"]}")
+            .add_argument(".schema")
+            .add_argument(".ids")
+            .expected_output("[{\"instance_path\": \"/1\", \"schema_path\": \"#/items/$ref/pattern\", \"message\": \"should match `^[a-z]+$`\"}]")
+            .explain("The `$ref` is resolved within the schema."),
+    )
+    .add_example(Example::new().add_argument("12").add_argument("12"))
+}
@@ -21,6 +21,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
     .add_description_line("Return the value of a named variable. See define for examples.")
     .add_example(Example::new().add_argument("\"foo\""))
 }
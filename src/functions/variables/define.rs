@@ -27,6 +27,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
         .add_alias("macro")
         .add_alias("def")
         .add_alias("#")
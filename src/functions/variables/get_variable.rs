@@ -21,6 +21,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
     .add_alias("get_variable")
     .add_description_line("Return the value of a named variable. See set for examples.")
     .add_example(Example::new().add_argument("\"foo\""))
@@ -27,6 +27,7 @@ pub fn get() -> FunctionDefinitions {
         }
         Rc::new(Impl(args))
     })
+    .add_argument_types(&["string"])
         .add_description_line(
             "Set a variable. The first argument should be the variable name, the second one should be the value and the third"
         )
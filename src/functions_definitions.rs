@@ -5,15 +5,12 @@ use std::rc::Rc;
 use std::sync::LazyLock;
 
 use crate::functions::all::group;
-#[cfg(feature = "create-docs")]
 use crate::json_parser::JsonParser;
 use crate::processor::Context;
-#[cfg(feature = "create-docs")]
 use crate::{reader::from_string, selection::Selection};
 
 use crate::{json_value::JsonValue, selection::Get};
 use clap::builder::PossibleValue;
-#[cfg(feature = "create-docs")]
 use std::str::FromStr;
 use thiserror::Error;
 
@@ -23,10 +20,10 @@ type Factory = fn(args: Vec<Rc<dyn Get>>) -> Rc<dyn Get>;
 pub enum FunctionDefinitionsError {
     #[error("Function '{0}' is unknown")]
     UnknownFunction(String),
-    #[error("Missing argument for {0}, got only {1} needed {2}")]
-    MissingArgument(String, usize, usize),
-    #[error("Too many arguments for {0}, got only {1} needed {2}")]
-    TooManyArgument(String, usize, usize),
+    #[error("Missing argument for {0}, got only {1} needed at least {2}, usage: {3}")]
+    MissingArgument(String, usize, usize, String),
+    #[error("Too many arguments for {0}, got {1} needed at most {2}, usage: {3}")]
+    TooManyArgument(String, usize, usize, String),
 }
 
 #[allow(dead_code)]
@@ -94,6 +91,8 @@ pub struct FunctionDefinitions {
     build_extractor: Factory,
     description: Vec<&'static str>,
     examples: Vec<Example>,
+    argument_types: Vec<&'static [&'static str]>,
+    rest_argument_types: &'static [&'static str],
 }
 
 impl FunctionDefinitions {
@@ -111,6 +110,8 @@ impl FunctionDefinitions {
             build_extractor,
             description: vec![],
             examples: vec![],
+            argument_types: vec![],
+            rest_argument_types: &[],
         }
     }
 
@@ -122,6 +123,16 @@ impl FunctionDefinitions {
         self.description.push(line);
         self
     }
+    /// Declare the types that the next argument can have, an empty list means any type.
+    pub fn add_argument_types(mut self, types: &'static [&'static str]) -> Self {
+        self.argument_types.push(types);
+        self
+    }
+    /// Declare the types that all the arguments after the declared ones can have.
+    pub fn add_rest_arguments_types(mut self, types: &'static [&'static str]) -> Self {
+        self.rest_argument_types = types;
+        self
+    }
     pub fn add_example(mut self, example: Example) -> Self {
         self.examples.push(example);
         self
@@ -141,20 +152,53 @@ impl FunctionDefinitions {
         if args.len() < self.min_args_count {
             return Err(FunctionDefinitionsError::MissingArgument(
                 self.name(),
-                self.min_args_count,
                 args.len(),
+                self.min_args_count,
+                self.signature(),
             ));
         }
         if args.len() > self.max_args_count {
             return Err(FunctionDefinitionsError::TooManyArgument(
                 self.name(),
                 args.len(),
-                self.min_args_count,
+                self.max_args_count,
+                self.signature(),
             ));
         }
         Ok((self.build_extractor)(args))
     }
 
+    /// The types that the argument at `index` is expected to have, empty if any type is accepted.
+    pub fn expected_types(&self, index: usize) -> &'static [&'static str] {
+        self.argument_types
+            .get(index)
+            .copied()
+            .unwrap_or(self.rest_argument_types)
+    }
+
+    pub fn signature(&self) -> String {
+        let mut signature = format!("({}", self.name);
+        for index in 0..self.min_args_count {
+            signature.push_str(&format!(" <arg{index}>"));
+        }
+        if self.max_args_count == usize::MAX {
+            signature.push_str(&format!(" [<arg{}>...]", self.min_args_count));
+        } else {
+            for index in self.min_args_count..self.max_args_count {
+                signature.push_str(&format!(" [<arg{index}>]"));
+            }
+        }
+        signature.push(')');
+        signature
+    }
+
+    fn matches(&self, term: &str) -> bool {
+        self.names()
+            .iter()
+            .chain(self.description.iter())
+            .any(|text| text.to_lowercase().contains(term))
+    }
+
     pub fn file_name(&self) -> String {
         let replacer = vec![
             ("?", "__qm__"),
@@ -227,7 +271,6 @@ impl FunctionsGroup {
     pub fn is_root(&self) -> bool {
         self.root
     }
-    #[cfg(feature = "create-docs")]
     pub fn subgroups(&self) -> impl Iterator<Item = &FunctionsGroup> {
         self.sub_groups.iter()
     }
@@ -255,6 +298,10 @@ static NAME_TO_FUNCTION: LazyLock<HashMap<&'static str, &'static FunctionDefinit
             .collect()
     });
 
+pub fn all_function_names() -> impl Iterator<Item = &'static str> {
+    NAME_TO_FUNCTION.keys().copied()
+}
+
 pub fn find_function(name: &str) -> Result<&'static FunctionDefinitions, FunctionDefinitionsError> {
     NAME_TO_FUNCTION
         .get(name)
@@ -284,7 +331,6 @@ pub fn create_possible_fn_help_types() -> Vec<PossibleValue> {
 
     values
 }
-#[cfg(feature = "create-docs")]
 pub fn get_fn_help(help_type: &str) -> Vec<String> {
     for group in ALL_GROUPS.all_sub_group_iter() {
         if group.group_name == help_type {
@@ -299,25 +345,61 @@ pub fn get_fn_help(help_type: &str) -> Vec<String> {
     }
 }
 
-pub fn get_fn_help_name(help_type: &str) -> String {
+pub fn get_functions_list() -> Vec<String> {
+    let mut help = Vec::new();
+    help.push("# Functions".to_string());
     for group in ALL_GROUPS.all_sub_group_iter() {
-        if group.group_name == help_type {
-            return help_type.into();
+        if group.group_functions.is_empty() {
+            continue;
+        }
+        help.push(String::new());
+        help.push(format!("## {}", group.group_name));
+        for function in &group.group_functions {
+            help.push(function_summary(function));
         }
     }
-    let function = NAME_TO_FUNCTION.get(help_type);
-    if let Some(&function) = function {
-        function.file_name()
+    help
+}
+
+pub fn search_functions(term: &str) -> Vec<String> {
+    let term = term.to_lowercase();
+    let mut help = Vec::new();
+    help.push(format!("# Functions matching `{term}`"));
+    let found: Vec<_> = ALL_GROUPS
+        .all_functions_iter()
+        .filter(|function| function.matches(&term))
+        .map(function_summary)
+        .collect();
+    if found.is_empty() {
+        help.push("No function found.".into());
     } else {
-        panic!("Can not find function {help_type}")
+        help.extend(found);
     }
+    help
+}
+
+fn function_summary(function: &FunctionDefinitions) -> String {
+    let mut summary = format!(
+        "* `{}` - {}",
+        function.signature(),
+        function.description.first().unwrap_or(&"")
+    );
+    if !function.aliases.is_empty() {
+        let aliases = function
+            .aliases
+            .iter()
+            .map(|alias| format!("`{alias}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        summary.push_str(&format!(" (Aliases: {aliases})"));
+    }
+    summary
 }
 
 #[cfg(feature = "create-docs")]
 pub fn get_groups() -> &'static FunctionsGroup {
     &ALL_GROUPS
 }
-#[cfg(feature = "create-docs")]
 fn create_group_detailed_help(group: &FunctionsGroup, indentation: String) -> Vec<String> {
     let mut help = Vec::new();
     for f in &group.group_functions {
@@ -329,7 +411,6 @@ fn create_group_detailed_help(group: &FunctionsGroup, indentation: String) -> Ve
             f.description.first().unwrap_or(&"")
         ));
     }
-    #[cfg(feature = "create-docs")]
     for g in group.subgroups() {
         help.push(format!(
             "{} Group [`{}`]({}.md) - {}",
@@ -345,7 +426,6 @@ fn create_group_detailed_help(group: &FunctionsGroup, indentation: String) -> Ve
     }
     help
 }
-#[cfg(feature = "create-docs")]
 fn get_group_help(group: &FunctionsGroup) -> Vec<String> {
     let mut help = Vec::new();
     if group.is_root() {
@@ -366,11 +446,12 @@ fn get_group_help(group: &FunctionsGroup) -> Vec<String> {
     );
     help
 }
-#[cfg(feature = "create-docs")]
 fn get_function_help(func: &FunctionDefinitions) -> Vec<String> {
     let mut help = Vec::new();
     let name = func.name;
     help.push(format!("# `{name}` function:"));
+    help.push(format!("Usage: `{}`", func.signature()));
+    help.push(String::new());
     for alias in &func.aliases {
         help.push(format!("* Can also be called as `{alias}`\n"));
     }
@@ -488,6 +569,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn argument_types_are_declared_with_valid_names() {
+        for func in ALL_GROUPS.all_functions_iter() {
+            println!("\t looking at function: {}", func.name);
+            assert!(func.argument_types.len() <= func.max_args_count);
+            for type_name in func
+                .argument_types
+                .iter()
+                .chain(iter::once(&func.rest_argument_types))
+                .flat_map(|types| types.iter())
+            {
+                assert!(matches!(
+                    *type_name,
+                    "null" | "boolean" | "string" | "number" | "object" | "array"
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn examples_use_the_declared_argument_types() -> selection::Result<()> {
+        for func in ALL_GROUPS.all_functions_iter() {
+            for example in func.examples.iter().filter(|e| e.output.is_some()) {
+                for (index, argument) in example.arguments.iter().enumerate() {
+                    // Only constants, as the other arguments depend on the input.
+                    if argument
+                        .trim_start()
+                        .starts_with(['(', ':', '@', '&', '/', '.', '#', '^'])
+                    {
+                        continue;
+                    }
+                    let context = Context::new_with_no_context(JsonValue::Null);
+                    let Some(value) = Selection::from_str(argument)?.get(&context) else {
+                        continue;
+                    };
+                    let expected = func.expected_types(index);
+                    println!("\t looking at {} argument {index}: {argument}", func.name);
+                    assert!(expected.is_empty() || expected.contains(&value.type_name().as_str()));
+                }
+            }
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_create_with_missing_of_arguments() -> selection::Result<()> {
         let func = find_function("?")?;
@@ -495,7 +620,7 @@ mod tests {
 
         assert!(matches!(
             error,
-            FunctionDefinitionsError::MissingArgument(_, _, _)
+            FunctionDefinitionsError::MissingArgument(_, _, _, _)
         ));
         Ok(())
     }
@@ -517,11 +642,45 @@ mod tests {
 
         assert!(matches!(
             error,
-            FunctionDefinitionsError::TooManyArgument(_, _, _)
+            FunctionDefinitionsError::TooManyArgument(_, _, _, _)
         ));
         Ok(())
     }
 
+    #[test]
+    fn signature_return_the_arguments() -> selection::Result<()> {
+        assert_eq!(find_function("now")?.signature(), "(now)");
+        assert_eq!(find_function("join")?.signature(), "(join <arg0> [<arg1>])");
+        assert_eq!(
+            find_function("+")?.signature(),
+            "(+ <arg0> <arg1> [<arg2>...])"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn search_functions_look_at_aliases_and_descriptions() {
+        let found = search_functions("ABSOLUTE");
+        assert!(found.iter().any(|l| l.starts_with("* `(abs <arg0>)`")));
+        assert!(found.iter().any(|l| l.ends_with("(Aliases: `nas_abs`)")));
+
+        let found = search_functions("multiple");
+        assert!(found.iter().any(|l| l.starts_with("* `(* <arg0>")));
+    }
+
+    #[test]
+    fn search_functions_report_nothing_found() {
+        let found = search_functions("no such function");
+        assert_eq!(found[1], "No function found.");
+    }
+
+    #[test]
+    fn get_functions_list_list_all_the_functions() {
+        let list = get_functions_list();
+        let count = list.iter().filter(|l| l.starts_with("* ")).count();
+        assert_eq!(count, ALL_GROUPS.all_functions_iter().count());
+    }
+
     #[test]
     fn name_return_the_function_name() -> selection::Result<()> {
         let func = find_function("if")?;
@@ -3,6 +3,7 @@ use std::{rc::Rc, str::FromStr};
 use indexmap::IndexMap;
 
 use crate::{
+    explain::Explanation,
     json_value::JsonValue,
     processor::{Context, Process, ProcessDecision, Result as ProcessResult, Titles},
     reader::from_string,
@@ -33,6 +34,14 @@ impl FromStr for Grouper {
 }
 
 impl Grouper {
+    pub fn explain(&self) -> Explanation {
+        Explanation::new("group by").with_child(self.group_by.explain())
+    }
+
+    pub fn uses_raw_input(&self) -> bool {
+        self.group_by.uses_raw_input()
+    }
+
     pub fn create_process(&self, next: Box<dyn Process>) -> Box<dyn Process> {
         Box::new(GrouperProcess {
             data: IndexMap::new(),
@@ -1,10 +1,12 @@
 use thiserror::Error;
 
 use crate::{
+    explain::Explanation,
     json_value::{JsonValue, NumberValue},
-    processor::Context,
+    processor::{Context, InputContext, InputFile, InputSource},
     reader::Reader,
-    selection::{Get, Result as SelectionResult},
+    selection::{Get, Result as SelectionResult, SelectionParseError},
+    strict::EvaluationError,
 };
 use std::{io::Read, rc::Rc};
 
@@ -14,7 +16,7 @@ pub enum InputContextExtractorParseError {
     UnknownType(String),
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 enum Type {
     Index,
     IndexInFile,
@@ -23,6 +25,40 @@ enum Type {
     EndsAtLineNumber,
     StartedAtCharNumber,
     EndAtCharNumber,
+    Raw,
+    ByteOffsetStart,
+    ByteOffsetEnd,
+    FilePath,
+    FileDir,
+    FileStem,
+    FileExtension,
+    FileSize,
+    FileModifiedTime,
+    FileIndex,
+}
+
+const TYPES: [(&str, Type); 17] = [
+    ("index", Type::Index),
+    ("index-in-file", Type::IndexInFile),
+    ("started-at-line-number", Type::StartedAtLineNumber),
+    ("started-at-char-number", Type::StartedAtCharNumber),
+    ("ended-at-line-number", Type::EndsAtLineNumber),
+    ("ended-at-char-number", Type::EndAtCharNumber),
+    ("file-name", Type::FileName),
+    ("raw", Type::Raw),
+    ("byte-offset-start", Type::ByteOffsetStart),
+    ("byte-offset-end", Type::ByteOffsetEnd),
+    ("file-path", Type::FilePath),
+    ("file-dir", Type::FileDir),
+    ("file-stem", Type::FileStem),
+    ("file-extension", Type::FileExtension),
+    ("file-size", Type::FileSize),
+    ("file-modified-time", Type::FileModifiedTime),
+    ("file-index", Type::FileIndex),
+];
+
+pub fn input_context_names() -> impl Iterator<Item = &'static str> {
+    TYPES.iter().map(|(name, _)| *name)
 }
 
 struct InputContextExtractor {
@@ -31,19 +67,41 @@ struct InputContextExtractor {
 
 impl InputContextExtractor {
     fn from_name(name: String) -> Result<Self, InputContextExtractorParseError> {
-        let extraction = match name.as_str() {
-            "index" => Type::Index,
-            "index-in-file" => Type::IndexInFile,
-            "started-at-line-number" => Type::StartedAtLineNumber,
-            "started-at-char-number" => Type::StartedAtCharNumber,
-            "ended-at-line-number" => Type::EndsAtLineNumber,
-            "ended-at-char-number" => Type::EndAtCharNumber,
-            "file-name" => Type::FileName,
-            _ => {
-                return Err(InputContextExtractorParseError::UnknownType(name));
-            }
+        let Some((_, extraction)) = TYPES.iter().find(|(n, _)| *n == name) else {
+            return Err(InputContextExtractorParseError::UnknownType(name));
         };
-        Ok(Self { extraction })
+        Ok(Self {
+            extraction: *extraction,
+        })
+    }
+}
+
+impl InputContextExtractor {
+    fn name(&self) -> &'static str {
+        TYPES
+            .iter()
+            .find(|(_, extraction)| *extraction == self.extraction)
+            .map_or("", |(name, _)| name)
+    }
+
+    fn source<'a>(&self, value: &Context, context: &'a InputContext) -> Option<&'a InputSource> {
+        if context.source.is_none() {
+            value.report(|| EvaluationError::NoInputContext(self.name().into()));
+        }
+        context.source.as_ref()
+    }
+
+    fn file_detail<F: Fn(&InputFile) -> Option<JsonValue>>(
+        &self,
+        value: &Context,
+        context: &InputContext,
+        detail: F,
+    ) -> Option<JsonValue> {
+        let detail = context.file.as_deref().and_then(detail);
+        if detail.is_none() {
+            value.report(|| EvaluationError::NoInputContext(self.name().into()));
+        }
+        detail
     }
 }
 
@@ -59,19 +117,74 @@ impl Get for InputContextExtractor {
                 Type::EndsAtLineNumber => Some(context.end_location.line_number.into()),
                 Type::StartedAtCharNumber => Some(context.start_location.char_number.into()),
                 Type::EndAtCharNumber => Some(context.end_location.char_number.into()),
-                Type::FileName => context
-                    .start_location
-                    .input
-                    .as_ref()
-                    .map(|str| str.clone().into()),
+                Type::FileName => {
+                    let file_name = context
+                        .start_location
+                        .input
+                        .as_ref()
+                        .map(|str| str.clone().into());
+                    if file_name.is_none() {
+                        value.report(|| EvaluationError::NoInputContext(self.name().into()));
+                    }
+                    file_name
+                }
+                Type::Raw => {
+                    let source = self.source(value, &context)?;
+                    if source.raw.is_none() {
+                        value.report(|| EvaluationError::NoInputContext(self.name().into()));
+                    }
+                    let raw = source.raw.as_deref()?;
+                    Some(String::from_utf8_lossy(raw).to_string().into())
+                }
+                Type::ByteOffsetStart => self.source(value, &context).map(|source| {
+                    JsonValue::Number(NumberValue::Positive(source.byte_offsets.start))
+                }),
+                Type::ByteOffsetEnd => self.source(value, &context).map(|source| {
+                    JsonValue::Number(NumberValue::Positive(source.byte_offsets.end))
+                }),
+                Type::FilePath => self.file_detail(value, &context, |file| {
+                    Some(file.path.to_string_lossy().to_string().into())
+                }),
+                Type::FileDir => self.file_detail(value, &context, |file| {
+                    file.path
+                        .parent()
+                        .map(|dir| dir.to_string_lossy().to_string().into())
+                }),
+                Type::FileStem => self.file_detail(value, &context, |file| {
+                    file.path
+                        .file_stem()
+                        .map(|stem| stem.to_string_lossy().to_string().into())
+                }),
+                Type::FileExtension => self.file_detail(value, &context, |file| {
+                    file.path
+                        .extension()
+                        .map(|extension| extension.to_string_lossy().to_string().into())
+                }),
+                Type::FileSize => self.file_detail(value, &context, |file| {
+                    Some(JsonValue::Number(NumberValue::Positive(file.size)))
+                }),
+                Type::FileModifiedTime => {
+                    self.file_detail(value, &context, |file| file.modified.map(Into::into))
+                }
+                Type::FileIndex => self.file_detail(value, &context, |file| {
+                    Some(JsonValue::Number(NumberValue::Positive(file.index)))
+                }),
             }
         } else {
+            value.report(|| EvaluationError::NoInputContext(self.name().into()));
             None
         }
     }
+    fn explain(&self) -> Explanation {
+        Explanation::new(format!("input context `&{}`", self.name()))
+    }
+    fn uses_raw_input(&self) -> bool {
+        self.extraction == Type::Raw
+    }
 }
 
 pub fn parse_input_context<R: Read>(reader: &mut Reader<R>) -> SelectionResult<Rc<dyn Get>> {
+    let location = reader.where_am_i();
     let mut name = Vec::new();
     while let Some(ch) = reader.next()? {
         if ch.is_ascii_lowercase() {
@@ -85,15 +198,19 @@ pub fn parse_input_context<R: Read>(reader: &mut Reader<R>) -> SelectionResult<R
         }
     }
     let name = String::from_utf8(name)?;
-    let getter = InputContextExtractor::from_name(name)?;
+    let getter = InputContextExtractor::from_name(name)
+        .map_err(|e| SelectionParseError::InputContextName(location, e))?;
     Ok(Rc::new(getter))
 }
 
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
     use crate::{
         reader::{Location, from_string},
         regex_cache::RegexCache,
+        selection::Selection,
     };
 
     use super::*;
@@ -133,6 +250,32 @@ mod tests {
         from_name_return_the_correct_name("file-name", &Type::FileName)
     }
 
+    #[test]
+    fn from_name_return_the_correct_name_raw() -> SelectionResult<()> {
+        from_name_return_the_correct_name("raw", &Type::Raw)
+    }
+
+    #[test]
+    fn from_name_return_the_correct_name_byte_offset_start() -> SelectionResult<()> {
+        from_name_return_the_correct_name("byte-offset-start", &Type::ByteOffsetStart)
+    }
+
+    #[test]
+    fn from_name_return_the_correct_name_byte_offset_end() -> SelectionResult<()> {
+        from_name_return_the_correct_name("byte-offset-end", &Type::ByteOffsetEnd)
+    }
+
+    #[test]
+    fn from_name_return_the_correct_name_file_details() -> SelectionResult<()> {
+        from_name_return_the_correct_name("file-path", &Type::FilePath)?;
+        from_name_return_the_correct_name("file-dir", &Type::FileDir)?;
+        from_name_return_the_correct_name("file-stem", &Type::FileStem)?;
+        from_name_return_the_correct_name("file-extension", &Type::FileExtension)?;
+        from_name_return_the_correct_name("file-size", &Type::FileSize)?;
+        from_name_return_the_correct_name("file-modified-time", &Type::FileModifiedTime)?;
+        from_name_return_the_correct_name("file-index", &Type::FileIndex)
+    }
+
     fn from_name_return_the_correct_name(name: &str, expected: &Type) -> SelectionResult<()> {
         let got = InputContextExtractor::from_name(name.to_string())?.extraction;
 
@@ -141,6 +284,21 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn only_selections_with_raw_use_the_raw_input() {
+        for (selection, expected) in [
+            ("&raw", true),
+            ("(len &raw)", true),
+            ("(if true (len &raw) 0)", true),
+            ("&index", false),
+            ("(len .raw)", false),
+        ] {
+            let selection = Selection::from_str(selection).unwrap();
+
+            assert_eq!(selection.uses_raw_input(), expected);
+        }
+    }
+
     #[test]
     fn from_name_return_error_for_unknown_name() {
         let err = InputContextExtractor::from_name("nop".to_string()).err();
@@ -348,6 +506,139 @@ mod tests {
         Ok(())
     }
 
+    fn context_with_source() -> Context {
+        let location = Location {
+            input: None,
+            line_number: 1,
+            char_number: 1,
+        };
+        Context::new_with_input(
+            JsonValue::Null,
+            location.clone(),
+            location,
+            0,
+            0,
+            &RegexCache::new(0),
+        )
+        .with_source(InputSource {
+            raw: Some(b"{\"a\":  1.50}".as_slice().into()),
+            byte_offsets: 12..25,
+        })
+    }
+
+    #[test]
+    fn get_return_the_correct_values_raw() -> SelectionResult<()> {
+        let ext = InputContextExtractor::from_name("raw".to_string())?;
+
+        assert_eq!(
+            ext.get(&context_with_source()),
+            Some("{\"a\":  1.50}".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_return_the_correct_values_byte_offsets() -> SelectionResult<()> {
+        let start = InputContextExtractor::from_name("byte-offset-start".to_string())?;
+        let end = InputContextExtractor::from_name("byte-offset-end".to_string())?;
+
+        assert_eq!(start.get(&context_with_source()), Some((12).into()));
+        assert_eq!(end.get(&context_with_source()), Some((25).into()));
+
+        Ok(())
+    }
+
+    fn context_with_file(path: &str) -> Context {
+        let location = Location {
+            input: Some(path.into()),
+            line_number: 1,
+            char_number: 1,
+        };
+        let file = Rc::new(InputFile {
+            path: path.into(),
+            size: 2048,
+            modified: Some(1701611515.5),
+            index: 3,
+        });
+        Context::new_with_input(
+            JsonValue::Null,
+            location.clone(),
+            location,
+            0,
+            0,
+            &RegexCache::new(0),
+        )
+        .with_input_file(&file)
+    }
+
+    fn get_file_detail(name: &str, path: &str) -> SelectionResult<Option<JsonValue>> {
+        let ext = InputContextExtractor::from_name(name.to_string())?;
+        Ok(ext.get(&context_with_file(path)))
+    }
+
+    #[test]
+    fn get_return_the_correct_values_file_details() -> SelectionResult<()> {
+        let path = "/data/date=2024-01-02/source.json";
+
+        assert_eq!(get_file_detail("file-path", path)?, Some(path.into()));
+        assert_eq!(
+            get_file_detail("file-dir", path)?,
+            Some("/data/date=2024-01-02".into())
+        );
+        assert_eq!(get_file_detail("file-stem", path)?, Some("source".into()));
+        assert_eq!(
+            get_file_detail("file-extension", path)?,
+            Some("json".into())
+        );
+        assert_eq!(get_file_detail("file-size", path)?, Some((2048).into()));
+        assert_eq!(
+            get_file_detail("file-modified-time", path)?,
+            Some(1701611515.5.into())
+        );
+        assert_eq!(get_file_detail("file-index", path)?, Some((3).into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_return_nothing_for_missing_extension() -> SelectionResult<()> {
+        assert_eq!(get_file_detail("file-extension", "/data/source")?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_return_nothing_without_file() -> SelectionResult<()> {
+        let ext = InputContextExtractor::from_name("file-stem".to_string())?;
+
+        assert_eq!(ext.get(&context_with_source()), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_return_nothing_without_source() -> SelectionResult<()> {
+        let ext = InputContextExtractor::from_name("raw".to_string())?;
+        let location = Location {
+            input: None,
+            line_number: 1,
+            char_number: 1,
+        };
+        let context = Context::new_with_input(
+            JsonValue::Null,
+            location.clone(),
+            location,
+            0,
+            0,
+            &RegexCache::new(0),
+        );
+
+        assert_eq!(ext.get(&context), None);
+
+        Ok(())
+    }
+
     #[test]
     fn get_return_notging_without_context() -> SelectionResult<()> {
         let ext = InputContextExtractor::from_name("index".to_string())?;
@@ -0,0 +1,336 @@
+use std::{
+    collections::HashSet,
+    fs::{read_dir, symlink_metadata},
+    io::Error as IoError,
+    path::{Component, Path, PathBuf},
+};
+
+use globset::{Error as GlobError, Glob, GlobBuilder, GlobMatcher, GlobSet, GlobSetBuilder};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum InputFilesError {
+    #[error("File `{0}` does not exist")]
+    NotFound(PathBuf),
+    #[error("Pattern `{0}` did not match any file")]
+    NoMatch(String),
+    #[error("Invalid pattern: {0}")]
+    Glob(#[from] GlobError),
+    #[error("{0}")]
+    Io(#[from] IoError),
+}
+
+pub type Result<T> = std::result::Result<T, InputFilesError>;
+
+#[derive(clap::ValueEnum, Debug, Clone, PartialEq)]
+#[clap(rename_all = "kebab_case")]
+pub enum Symlinks {
+    /// Read the files and directories that the links point to.
+    Follow,
+    /// Ignore the symbolic links.
+    Skip,
+}
+
+/// List the input files from the command line arguments.
+///
+/// Arguments can be files, directories (that will be traversed in sorted order) or glob patterns (like
+/// `logs/**/*.json`). The files found within directories and by patterns are filtered by the include and
+/// exclude patterns.
+pub struct InputFiles {
+    include: Option<GlobSet>,
+    exclude: Option<GlobSet>,
+    max_depth: Option<usize>,
+    symlinks: Symlinks,
+}
+
+impl InputFiles {
+    pub fn new(
+        include: &[String],
+        exclude: &[String],
+        max_depth: Option<usize>,
+        symlinks: Symlinks,
+    ) -> Result<Self> {
+        Ok(InputFiles {
+            include: build_glob_set(include)?,
+            exclude: build_glob_set(exclude)?,
+            max_depth,
+            symlinks,
+        })
+    }
+
+    pub fn list(&self, arguments: &[PathBuf]) -> Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+        for argument in arguments {
+            if argument.exists() {
+                if argument.is_dir() {
+                    self.walk(
+                        argument,
+                        self.max_depth,
+                        1,
+                        &mut HashSet::new(),
+                        &mut |path| {
+                            files.push(path.to_path_buf());
+                        },
+                    )?;
+                } else {
+                    files.push(argument.clone());
+                }
+            } else if let Some(pattern) = glob_pattern(argument) {
+                let found = self.expand(&pattern)?;
+                if found.is_empty() {
+                    return Err(InputFilesError::NoMatch(pattern));
+                }
+                files.extend(found);
+            } else {
+                return Err(InputFilesError::NotFound(argument.clone()));
+            }
+        }
+        Ok(files)
+    }
+
+    fn expand(&self, pattern: &str) -> Result<Vec<PathBuf>> {
+        let matcher = GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .build()?
+            .compile_matcher();
+        let base = base_directory(pattern);
+        let max_depth = match (self.max_depth, pattern_depth(pattern)) {
+            (Some(max_depth), Some(depth)) => Some(max_depth.min(depth)),
+            (max_depth, depth) => max_depth.or(depth),
+        };
+        let mut found = Vec::new();
+        if base.is_dir() {
+            self.walk(&base, max_depth, 1, &mut HashSet::new(), &mut |path| {
+                if matches(&matcher, path) {
+                    found.push(path.to_path_buf());
+                }
+            })?;
+        }
+        Ok(found)
+    }
+
+    fn walk<F: FnMut(&Path)>(
+        &self,
+        directory: &Path,
+        max_depth: Option<usize>,
+        depth: usize,
+        visited: &mut HashSet<PathBuf>,
+        found: &mut F,
+    ) -> Result<()> {
+        if !visited.insert(directory.canonicalize()?) {
+            return Ok(());
+        }
+        let mut entries = read_dir(directory)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<std::io::Result<Vec<_>>>()?;
+        entries.sort();
+        for path in entries {
+            if self.symlinks == Symlinks::Skip && symlink_metadata(&path)?.is_symlink() {
+                continue;
+            }
+            if self.exclude.as_ref().is_some_and(|set| set.is_match(&path)) {
+                continue;
+            }
+            if path.is_dir() {
+                if max_depth.is_none_or(|max_depth| depth < max_depth) {
+                    self.walk(&path, max_depth, depth + 1, visited, found)?;
+                }
+            } else if path.exists() && self.include.as_ref().is_none_or(|set| set.is_match(&path)) {
+                found(&path);
+            }
+        }
+        visited.remove(&directory.canonicalize()?);
+        Ok(())
+    }
+}
+
+fn build_glob_set(patterns: &[String]) -> Result<Option<GlobSet>> {
+    if patterns.is_empty() {
+        return Ok(None);
+    }
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(Glob::new(pattern)?);
+    }
+    Ok(Some(builder.build()?))
+}
+
+fn glob_pattern(argument: &Path) -> Option<String> {
+    let pattern = argument.to_str()?;
+    if pattern.contains(['*', '?', '[', '{']) {
+        Some(pattern.to_string())
+    } else {
+        None
+    }
+}
+
+fn base_directory(pattern: &str) -> PathBuf {
+    let mut base = PathBuf::new();
+    for component in Path::new(pattern).components() {
+        if let Component::Normal(name) = component
+            && name.to_string_lossy().contains(['*', '?', '[', '{'])
+        {
+            break;
+        }
+        base.push(component);
+    }
+    if base.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        base
+    }
+}
+
+/// How deep below the base directory the pattern can match, or nothing if it has a `**`.
+fn pattern_depth(pattern: &str) -> Option<usize> {
+    let components: Vec<_> = Path::new(pattern).components().collect();
+    if components
+        .iter()
+        .any(|component| component.as_os_str() == "**")
+    {
+        return None;
+    }
+    let first_pattern = components.iter().position(|component| {
+        matches!(component, Component::Normal(name) if name.to_string_lossy().contains(['*', '?', '[', '{']))
+    })?;
+    Some(components.len() - first_pattern)
+}
+
+fn matches(matcher: &GlobMatcher, path: &Path) -> bool {
+    matcher.is_match(path)
+        || path
+            .strip_prefix(".")
+            .is_ok_and(|path| matcher.is_match(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env::temp_dir, fs};
+
+    use super::*;
+
+    fn create_tree(name: &str) -> Result<PathBuf> {
+        let root = temp_dir().join(format!("jawk-input-files-{}-{}", name, std::process::id()));
+        fs::remove_dir_all(&root).ok();
+        fs::create_dir_all(root.join("b/deep"))?;
+        fs::create_dir_all(root.join("a"))?;
+        for file in [
+            "z.json",
+            "a/2.json",
+            "a/1.json",
+            "a/skip.txt",
+            "b/deep/3.json",
+        ] {
+            fs::write(root.join(file), "{}")?;
+        }
+        Ok(root)
+    }
+
+    fn relative(root: &Path, files: Vec<PathBuf>) -> Vec<String> {
+        files
+            .iter()
+            .map(|file| {
+                file.strip_prefix(root)
+                    .unwrap()
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn list_traverse_directories_in_order() -> Result<()> {
+        let root = create_tree("order")?;
+        let files =
+            InputFiles::new(&[], &[], None, Symlinks::Follow)?.list(std::slice::from_ref(&root))?;
+
+        assert_eq!(
+            relative(&root, files),
+            vec![
+                "a/1.json",
+                "a/2.json",
+                "a/skip.txt",
+                "b/deep/3.json",
+                "z.json"
+            ]
+        );
+        fs::remove_dir_all(root)?;
+        Ok(())
+    }
+
+    #[test]
+    fn list_apply_the_filters_and_depth() -> Result<()> {
+        let root = create_tree("filters")?;
+        let input_files = InputFiles::new(
+            &["*.json".to_string()],
+            &["**/a/2.json".to_string()],
+            Some(2),
+            Symlinks::Follow,
+        )?;
+
+        let files = input_files.list(std::slice::from_ref(&root))?;
+
+        assert_eq!(relative(&root, files), vec!["a/1.json", "z.json"]);
+        fs::remove_dir_all(root)?;
+        Ok(())
+    }
+
+    #[test]
+    fn list_expand_glob_patterns() -> Result<()> {
+        let root = create_tree("glob")?;
+        let pattern = root.join("**").join("*.json");
+
+        let files = InputFiles::new(&[], &[], None, Symlinks::Follow)?.list(&[pattern])?;
+
+        assert_eq!(
+            relative(&root, files),
+            vec!["a/1.json", "a/2.json", "b/deep/3.json", "z.json"]
+        );
+        fs::remove_dir_all(root)?;
+        Ok(())
+    }
+
+    #[test]
+    fn list_expand_glob_patterns_only_to_their_depth() -> Result<()> {
+        let root = create_tree("glob-depth")?;
+        let input_files = InputFiles::new(&[], &[], None, Symlinks::Follow)?;
+
+        let files = input_files.list(&[root.join("*").join("*.json")])?;
+        assert_eq!(relative(&root, files), vec!["a/1.json", "a/2.json"]);
+
+        let input_files = InputFiles::new(&[], &[], Some(2), Symlinks::Follow)?;
+        let files = input_files.list(&[root.join("**").join("*.json")])?;
+        assert_eq!(
+            relative(&root, files),
+            vec!["a/1.json", "a/2.json", "z.json"]
+        );
+        fs::remove_dir_all(root)?;
+        Ok(())
+    }
+
+    #[test]
+    fn list_fails_for_missing_files() -> Result<()> {
+        let input_files = InputFiles::new(&[], &[], None, Symlinks::Follow)?;
+
+        let error = input_files.list(&[PathBuf::from("no/such/file.json")]);
+
+        assert!(matches!(error, Err(InputFilesError::NotFound(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn base_directory_stop_at_the_first_pattern() {
+        assert_eq!(
+            base_directory("logs/2024/**/*.json"),
+            PathBuf::from("logs/2024")
+        );
+        assert_eq!(base_directory("*.json"), PathBuf::from("."));
+    }
+
+    #[test]
+    fn pattern_depth_count_the_components_after_the_base() {
+        assert_eq!(pattern_depth("logs/2024/*/*.json"), Some(2));
+        assert_eq!(pattern_depth("*.json"), Some(1));
+        assert_eq!(pattern_depth("logs/**/*.json"), None);
+    }
+}
@@ -6,12 +6,32 @@ use indexmap::IndexMap;
 use thiserror::Error;
 
 use crate::json_value::{JsonValue, NumberValue};
-use crate::reader::{Location, Reader};
+use crate::reader::{Location, RECORD_SEPARATOR, Reader};
 
 pub type Result<T> = std::result::Result<T, JsonParserError>;
 
 pub trait JsonParser {
     fn next_json_value(&mut self) -> Result<Option<JsonValue>>;
+    /// Read the next value, failing if the value did not end in the line in which it started.
+    fn next_json_value_in_line(&mut self) -> Result<Option<JsonValue>>;
+    /// Skip the input after an error up to the point from which the parsing can resume.
+    ///
+    /// Return the number of bytes that were skipped.
+    fn recover(&mut self, strategy: &RecoveryStrategy) -> Result<usize>;
+}
+
+/// Where to resume the parsing after an invalid input.
+#[derive(clap::ValueEnum, Debug, Clone, PartialEq)]
+#[clap(rename_all = "kebab_case")]
+pub enum RecoveryStrategy {
+    /// Resume right after the character that caused the error.
+    Character,
+    /// Skip to the next line, a value can not span multiple lines (for NDJSON input).
+    Line,
+    /// Skip to the next `{` or `[`.
+    Value,
+    /// Skip to the next record separator (for JSON text sequences, RFC 7464).
+    RecordSeparator,
 }
 
 trait JsonParserUtils {
@@ -238,6 +258,11 @@ impl<R: Read> JsonParserUtils for Reader<R> {
                 None => {
                     return Err(JsonParserError::UnexpectedEof(self.where_am_i()));
                 }
+                Some(RECORD_SEPARATOR) => {
+                    return Err(JsonParserError::UnexpectedRecordSeparator(
+                        self.where_am_i(),
+                    ));
+                }
                 Some(b'\"') => {
                     self.next()?;
                     match String::from_utf8(chars) {
@@ -351,6 +376,9 @@ impl<R: Read> JsonParser for Reader<R> {
             Some(b'-' | b'0'..=b'9') => Ok(Some(self.read_number()?)),
             Some(b'[') => Ok(Some(self.read_array()?)),
             Some(b'{') => Ok(Some(self.read_object()?)),
+            Some(RECORD_SEPARATOR) => Err(JsonParserError::UnexpectedRecordSeparator(
+                self.where_am_i(),
+            )),
             Some(ch) => {
                 self.next()?;
                 let mut expected = vec!['n', 't', 'f', '\"', '-', '[', '{'];
@@ -360,6 +388,42 @@ impl<R: Read> JsonParser for Reader<R> {
             }
         }
     }
+
+    fn next_json_value_in_line(&mut self) -> Result<Option<JsonValue>> {
+        self.eat_whitespace()?;
+        let mut location = self.where_am_i();
+        let start_offset = self.byte_offset();
+        self.stop_at_new_line(true);
+        let value = self.next_json_value();
+        self.stop_at_new_line(false);
+        match value {
+            Err(JsonParserError::UnexpectedEof(_)) if self.peek()?.is_some() => {
+                // The new line was already read, so the location is of the end of the value's line.
+                location.char_number += (self.byte_offset() - start_offset) as usize;
+                Err(JsonParserError::UnexpectedEndOfLine(location))
+            }
+            value => value,
+        }
+    }
+
+    fn recover(&mut self, strategy: &RecoveryStrategy) -> Result<usize> {
+        let mut skipped = 0;
+        loop {
+            match (strategy, self.peek()?) {
+                (RecoveryStrategy::Character, _)
+                | (_, None)
+                | (_, Some(RECORD_SEPARATOR))
+                | (RecoveryStrategy::Value, Some(b'{' | b'['))
+                | (RecoveryStrategy::Line, Some(b'\n')) => {
+                    return Ok(skipped);
+                }
+                _ => {
+                    self.next()?;
+                    skipped += 1;
+                }
+            }
+        }
+    }
 }
 
 fn create_unexpected_character<R: Read, T: IntoIterator<Item = char>>(
@@ -400,18 +464,49 @@ pub enum JsonParserError {
     InvalidChacterHex(Location, u32),
     #[error("{0}: Unexpected end of file")]
     UnexpectedEof(Location),
+    #[error("{0}: Unexpected end of line")]
+    UnexpectedEndOfLine(Location),
+    #[error("{0}: Unexpected record separator")]
+    UnexpectedRecordSeparator(Location),
     #[error("{0}: Only string keys are supported, not keys of type: {1}")]
     StringKeyMissing(Location, String),
 }
 
+/// An invalid input, after which the parser skipped to a point from which it could resume.
+#[derive(Debug, Error)]
+#[error("{error} (skipped {skipped} bytes)")]
+pub struct SkippedInput {
+    pub error: JsonParserError,
+    pub skipped: usize,
+}
+
 impl JsonParserError {
     pub fn can_recover(&self) -> bool {
         !matches!(self, JsonParserError::IoError(_))
     }
+
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            JsonParserError::IoError(_) => None,
+            JsonParserError::StringUtfError(location, _)
+            | JsonParserError::NumberParseIntError(location, _)
+            | JsonParserError::NumberParseFloatError(location, _)
+            | JsonParserError::NumberParseInfiniteNumber(location, _)
+            | JsonParserError::IncompleteReservedWord(location, _, _, _)
+            | JsonParserError::UnexpectedCharacter(location, _, _)
+            | JsonParserError::InvalidChacterHex(location, _)
+            | JsonParserError::UnexpectedEof(location)
+            | JsonParserError::UnexpectedEndOfLine(location)
+            | JsonParserError::UnexpectedRecordSeparator(location)
+            | JsonParserError::StringKeyMissing(location, _) => Some(location),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
     use crate::reader::from_string;
 
     use super::*;
@@ -884,4 +979,95 @@ mod tests {
             Err(JsonParserError::UnexpectedCharacter(_, _, _))
         ));
     }
+
+    #[test]
+    fn value_in_line_fail_on_new_line() -> Result<()> {
+        let str = "\n{\"a\": 1}\n{\"a\": \"trunc\n{\"a\": 2}".to_string();
+        let mut reader = from_string(&str);
+
+        assert_eq!(
+            reader.next_json_value_in_line()?,
+            Some(JsonValue::from_str(r#"{"a": 1}"#)?)
+        );
+        assert!(matches!(
+            reader.next_json_value_in_line(),
+            Err(JsonParserError::UnexpectedEndOfLine(Location {
+                line_number: 3,
+                char_number: 14,
+                ..
+            }))
+        ));
+        assert_eq!(reader.recover(&RecoveryStrategy::Line)?, 0);
+        assert_eq!(
+            reader.next_json_value_in_line()?,
+            Some(JsonValue::from_str(r#"{"a": 2}"#)?)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn recover_to_next_line() -> Result<()> {
+        let str = "{\"a\": x, \"b\": 2}\n3".to_string();
+        let mut reader = from_string(&str);
+
+        assert!(reader.next_json_value_in_line().is_err());
+        assert_eq!(reader.recover(&RecoveryStrategy::Line)?, 9);
+        assert_eq!(reader.next_json_value_in_line()?, Some(3.into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn recover_to_next_value() -> Result<()> {
+        let str = "{\"a\": x, \"b\": 2} [3]".to_string();
+        let mut reader = from_string(&str);
+
+        assert!(reader.next_json_value().is_err());
+        assert_eq!(reader.recover(&RecoveryStrategy::Value)?, 10);
+        assert_eq!(
+            reader.next_json_value()?,
+            Some(JsonValue::Array(vec![3.into()]))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn record_separator_end_an_incomplete_value() -> Result<()> {
+        let str = "\u{1e}{\"a\": \"x\u{1e}{\"b\": [\u{1e}3\n".to_string();
+        let mut reader = from_string(&str);
+
+        reader.eat_record_separators()?;
+        assert!(matches!(
+            reader.next_json_value(),
+            Err(JsonParserError::UnexpectedRecordSeparator(_))
+        ));
+        reader.eat_record_separators()?;
+        assert!(matches!(
+            reader.next_json_value(),
+            Err(JsonParserError::UnexpectedRecordSeparator(_))
+        ));
+        reader.eat_record_separators()?;
+        assert_eq!(reader.next_json_value()?, Some(3.into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn recover_to_next_record_separator() -> Result<()> {
+        let str = "\u{1e}{\"a\": x, \"b\": 2}\n\u{1e}[3]\n".to_string();
+        let mut reader = from_string(&str);
+
+        reader.eat_record_separators()?;
+        assert!(reader.next_json_value().is_err());
+        assert_eq!(reader.recover(&RecoveryStrategy::RecordSeparator)?, 10);
+        reader.eat_record_separators()?;
+        assert_eq!(
+            reader.next_json_value()?,
+            Some(JsonValue::Array(vec![3.into()]))
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,639 @@
+use std::{
+    cell::{Cell, RefCell},
+    fmt::Display,
+    io::Error as IoError,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use bigdecimal::{BigDecimal, Signed, Zero};
+use indexmap::{IndexMap, IndexSet};
+use thiserror::Error;
+
+use crate::{
+    json_parser::{JsonParser, JsonParserError},
+    json_value::{JsonValue, NumberValue},
+    reader::from_file,
+    regex_cache::RegexCompile,
+};
+
+const MAX_REFERENCES_DEPTH: usize = 64;
+const MAX_EVALUATIONS_PER_NODE: usize = 10_000;
+
+#[derive(Debug, Error)]
+pub enum SchemaError {
+    #[error("Could not read schema `{0}`: {1}")]
+    Io(PathBuf, IoError),
+    #[error("Could not parse schema `{0}`: {1}")]
+    Json(PathBuf, JsonParserError),
+    #[error("Schema `{0}` should contain a single JSON value")]
+    NotSingleValue(PathBuf),
+}
+
+/// Read a JSON schema document from a file.
+pub fn load_schema(path: &Path) -> Result<JsonValue, SchemaError> {
+    let mut reader = from_file(&path.to_path_buf()).map_err(|e| SchemaError::Io(path.into(), e))?;
+    let schema = reader
+        .next_json_value()
+        .map_err(|e| SchemaError::Json(path.into(), e))?;
+    match (schema, reader.next_json_value()) {
+        (Some(schema), Ok(None)) => Ok(schema),
+        _ => Err(SchemaError::NotSingleValue(path.into())),
+    }
+}
+
+/// A single reason for a value not to match a schema.
+#[derive(Debug, Error, PartialEq)]
+pub struct Violation {
+    /// A JSON pointer to the invalid part of the value.
+    pub instance_path: String,
+    /// A JSON pointer (as a URI fragment) to the keyword of the schema that was not satisfied.
+    pub schema_path: String,
+    pub message: String,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.instance_path.is_empty() {
+            write!(f, "`{}` ", self.instance_path)?;
+        }
+        write!(f, "{} (at `{}`)", self.message, self.schema_path)
+    }
+}
+
+impl From<&Violation> for JsonValue {
+    fn from(violation: &Violation) -> Self {
+        let mut object = IndexMap::with_capacity(3);
+        object.insert(
+            "instance_path".to_string(),
+            violation.instance_path.clone().into(),
+        );
+        object.insert(
+            "schema_path".to_string(),
+            violation.schema_path.clone().into(),
+        );
+        object.insert("message".to_string(), violation.message.clone().into());
+        object.into()
+    }
+}
+
+/// Check the value against the schema, and return all the violations (nothing if the value is valid).
+///
+/// Supported keywords are the commonly used ones from draft 2020-12: `type`, `enum`, `const`, `minLength`,
+/// `maxLength`, `pattern`, `minimum`, `maximum`, `exclusiveMinimum`, `exclusiveMaximum`, `multipleOf`,
+/// `items`, `prefixItems`, `minItems`, `maxItems`, `uniqueItems`, `properties`, `required`,
+/// `additionalProperties`, `allOf`, `anyOf`, `oneOf`, `not` and `$ref` (within the same document). Other
+/// keywords are ignored.
+///
+/// A reference that loops back to itself for the same part of the value is a violation, and so is a schema that
+/// needs too many checks for the size of the value (as the sub schemas can fan out exponentially).
+pub fn validate(schema: &JsonValue, value: &JsonValue, regex: &dyn RegexCompile) -> Vec<Violation> {
+    let validator = Validator {
+        root: schema,
+        regex,
+        max_evaluations: count_nodes(value).saturating_mul(MAX_EVALUATIONS_PER_NODE),
+        evaluations: Cell::new(0),
+        references: RefCell::default(),
+    };
+    let mut violations = vec![];
+    validator.check(schema, value, "", "#", 0, &mut violations);
+    if validator.evaluations.get() > validator.max_evaluations {
+        violations.push(Violation {
+            instance_path: String::new(),
+            schema_path: "#".to_string(),
+            message: "needs too many checks to validate".to_string(),
+        });
+    }
+    violations
+}
+
+fn count_nodes(value: &JsonValue) -> usize {
+    match value {
+        JsonValue::Array(list) => 1 + list.iter().map(count_nodes).sum::<usize>(),
+        JsonValue::Object(map) => 1 + map.values().map(count_nodes).sum::<usize>(),
+        _ => 1,
+    }
+}
+
+struct Validator<'a> {
+    root: &'a JsonValue,
+    regex: &'a dyn RegexCompile,
+    max_evaluations: usize,
+    evaluations: Cell<usize>,
+    /// The references that are being followed, with the part of the value they are applied to.
+    references: RefCell<Vec<(*const JsonValue, *const JsonValue)>>,
+}
+
+fn escape(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+fn unescape(token: &str) -> String {
+    token.replace("~1", "/").replace("~0", "~")
+}
+
+fn as_f64(value: &JsonValue) -> Option<f64> {
+    match value {
+        JsonValue::Number(number) => Some(number.into()),
+        _ => None,
+    }
+}
+
+/// The number as an exact decimal, so `0.3` is a multiple of `0.1` (floats are taken as their shortest
+/// representation).
+fn as_big_decimal(value: &JsonValue) -> Option<BigDecimal> {
+    match value {
+        JsonValue::Number(NumberValue::Negative(number)) => Some((*number).into()),
+        JsonValue::Number(NumberValue::Positive(number)) => Some((*number).into()),
+        JsonValue::Number(NumberValue::Float(number)) if number.is_finite() => {
+            BigDecimal::from_str(&number.to_string()).ok()
+        }
+        _ => None,
+    }
+}
+
+fn as_usize(value: &JsonValue) -> Option<usize> {
+    match value {
+        JsonValue::Number(NumberValue::Positive(number)) => Some(*number as usize),
+        _ => None,
+    }
+}
+
+fn has_type(value: &JsonValue, type_name: &str) -> bool {
+    match (type_name, value) {
+        ("integer", JsonValue::Number(NumberValue::Float(f))) => f.fract() == 0.0,
+        ("integer", JsonValue::Number(_)) => true,
+        (type_name, value) => value.type_name() == type_name,
+    }
+}
+
+impl Validator<'_> {
+    fn resolve(&self, reference: &str) -> Option<&JsonValue> {
+        let pointer = reference.strip_prefix('#')?;
+        let mut schema = self.root;
+        for token in pointer.split('/').skip(1) {
+            let token = unescape(token);
+            schema = match schema {
+                JsonValue::Object(map) => map.get(&token)?,
+                JsonValue::Array(list) => list.get(token.parse::<usize>().ok()?)?,
+                _ => return None,
+            };
+        }
+        Some(schema)
+    }
+
+    fn is_valid(&self, schema: &JsonValue, value: &JsonValue, depth: usize) -> bool {
+        let mut violations = vec![];
+        self.check(schema, value, "", "#", depth, &mut violations);
+        violations.is_empty()
+    }
+
+    fn check(
+        &self,
+        schema: &JsonValue,
+        value: &JsonValue,
+        instance_path: &str,
+        schema_path: &str,
+        depth: usize,
+        violations: &mut Vec<Violation>,
+    ) {
+        let evaluations = self.evaluations.get() + 1;
+        self.evaluations.set(evaluations);
+        if evaluations > self.max_evaluations {
+            return;
+        }
+        let violation = |keyword: &str, message: String| Violation {
+            instance_path: instance_path.to_string(),
+            schema_path: format!("{schema_path}{keyword}"),
+            message,
+        };
+        let schema = match schema {
+            JsonValue::Boolean(false) => {
+                violations.push(violation("", "is not allowed".to_string()));
+                return;
+            }
+            JsonValue::Object(schema) => schema,
+            _ => return,
+        };
+        if let Some(JsonValue::String(reference)) = schema.get("$ref") {
+            if let Some(referenced) = self.resolve(reference) {
+                let key = (referenced as *const JsonValue, value as *const JsonValue);
+                if depth >= MAX_REFERENCES_DEPTH || self.references.borrow().contains(&key) {
+                    let message = "has too many nested references".to_string();
+                    violations.push(violation("/$ref", message));
+                } else {
+                    self.references.borrow_mut().push(key);
+                    let path = format!("{schema_path}/$ref");
+                    self.check(
+                        referenced,
+                        value,
+                        instance_path,
+                        &path,
+                        depth + 1,
+                        violations,
+                    );
+                    self.references.borrow_mut().pop();
+                }
+            } else {
+                let message = format!("can not resolve reference `{reference}`");
+                violations.push(violation("/$ref", message));
+            }
+        }
+        self.check_assertions(schema, value, depth, &mut |keyword, message| {
+            violations.push(violation(&format!("/{keyword}"), message));
+        });
+        self.check_applicators(schema, value, instance_path, schema_path, depth, violations);
+    }
+
+    /// Check the keywords that apply to the value itself.
+    fn check_assertions(
+        &self,
+        schema: &IndexMap<String, JsonValue>,
+        value: &JsonValue,
+        depth: usize,
+        fail: &mut dyn FnMut(&str, String),
+    ) {
+        match schema.get("type") {
+            Some(JsonValue::String(type_name)) if !has_type(value, type_name) => {
+                fail(
+                    "type",
+                    format!("should be {type_name}, got {}", value.type_name()),
+                );
+            }
+            Some(JsonValue::Array(types))
+                if !types.iter().any(
+                    |type_name| matches!(type_name, JsonValue::String(t) if has_type(value, t)),
+                ) =>
+            {
+                let types: Vec<String> = types.iter().map(ToString::to_string).collect();
+                fail(
+                    "type",
+                    format!(
+                        "should be one of {}, got {}",
+                        types.join(", "),
+                        value.type_name()
+                    ),
+                );
+            }
+            _ => {}
+        }
+        if let Some(JsonValue::Array(values)) = schema.get("enum")
+            && !values.contains(value)
+        {
+            fail("enum", "is not one of the allowed values".to_string());
+        }
+        if let Some(expected) = schema.get("const")
+            && expected != value
+        {
+            fail("const", format!("should be {expected}"));
+        }
+        if let JsonValue::String(str) = value {
+            let length = str.chars().count();
+            if let Some(min) = schema.get("minLength").and_then(as_usize)
+                && length < min
+            {
+                fail(
+                    "minLength",
+                    format!("should have at least {min} characters"),
+                );
+            }
+            if let Some(max) = schema.get("maxLength").and_then(as_usize)
+                && length > max
+            {
+                fail("maxLength", format!("should have at most {max} characters"));
+            }
+            if let Some(JsonValue::String(pattern)) = schema.get("pattern") {
+                match &*self.regex.compile_regex(pattern) {
+                    Ok(regex) if regex.is_match(str) => {}
+                    Ok(_) => fail("pattern", format!("should match `{pattern}`")),
+                    Err(e) => fail("pattern", format!("has an invalid pattern: {e}")),
+                }
+            }
+        }
+        if let Some(number) = as_f64(value) {
+            if let Some(min) = schema.get("minimum").and_then(as_f64)
+                && number < min
+            {
+                fail(
+                    "minimum",
+                    format!("should be at least {}", JsonValue::from(min)),
+                );
+            }
+            if let Some(max) = schema.get("maximum").and_then(as_f64)
+                && number > max
+            {
+                fail(
+                    "maximum",
+                    format!("should be at most {}", JsonValue::from(max)),
+                );
+            }
+            if let Some(min) = schema.get("exclusiveMinimum").and_then(as_f64)
+                && number <= min
+            {
+                let message = format!("should be greater than {}", JsonValue::from(min));
+                fail("exclusiveMinimum", message);
+            }
+            if let Some(max) = schema.get("exclusiveMaximum").and_then(as_f64)
+                && number >= max
+            {
+                let message = format!("should be less than {}", JsonValue::from(max));
+                fail("exclusiveMaximum", message);
+            }
+            if let Some(multiple) = schema.get("multipleOf").and_then(as_big_decimal)
+                && multiple.is_positive()
+                && let Some(number) = as_big_decimal(value)
+                && !(number % &multiple).is_zero()
+            {
+                let message = format!("should be a multiple of {}", multiple.normalized());
+                fail("multipleOf", message);
+            }
+        }
+        if let JsonValue::Array(list) = value {
+            if let Some(min) = schema.get("minItems").and_then(as_usize)
+                && list.len() < min
+            {
+                fail("minItems", format!("should have at least {min} items"));
+            }
+            if let Some(max) = schema.get("maxItems").and_then(as_usize)
+                && list.len() > max
+            {
+                fail("maxItems", format!("should have at most {max} items"));
+            }
+            if let Some(JsonValue::Boolean(true)) = schema.get("uniqueItems")
+                && list.iter().collect::<IndexSet<_>>().len() < list.len()
+            {
+                fail("uniqueItems", "should not have duplicate items".to_string());
+            }
+        }
+        if let JsonValue::Object(map) = value
+            && let Some(JsonValue::Array(required)) = schema.get("required")
+        {
+            for key in required {
+                if let JsonValue::String(key) = key
+                    && !map.contains_key(key)
+                {
+                    fail("required", format!("should have the property `{key}`"));
+                }
+            }
+        }
+        if let Some(JsonValue::Array(schemas)) = schema.get("anyOf")
+            && !schemas
+                .iter()
+                .any(|sub_schema| self.is_valid(sub_schema, value, depth))
+        {
+            fail("anyOf", "should match at least one schema".to_string());
+        }
+        if let Some(JsonValue::Array(schemas)) = schema.get("oneOf") {
+            let matches = schemas
+                .iter()
+                .filter(|sub_schema| self.is_valid(sub_schema, value, depth))
+                .count();
+            if matches != 1 {
+                let message = format!("should match exactly one schema, matched {matches}");
+                fail("oneOf", message);
+            }
+        }
+        if let Some(sub_schema) = schema.get("not")
+            && self.is_valid(sub_schema, value, depth)
+        {
+            fail("not", "should not match the schema".to_string());
+        }
+    }
+
+    /// Check the keywords that apply sub schemas to the value or to its items.
+    fn check_applicators(
+        &self,
+        schema: &IndexMap<String, JsonValue>,
+        value: &JsonValue,
+        instance_path: &str,
+        schema_path: &str,
+        depth: usize,
+        violations: &mut Vec<Violation>,
+    ) {
+        if let JsonValue::Array(list) = value {
+            let mut prefix = 0;
+            if let Some(JsonValue::Array(prefix_items)) = schema.get("prefixItems") {
+                for (index, (item, item_schema)) in list.iter().zip(prefix_items).enumerate() {
+                    self.check(
+                        item_schema,
+                        item,
+                        &format!("{instance_path}/{index}"),
+                        &format!("{schema_path}/prefixItems/{index}"),
+                        depth,
+                        violations,
+                    );
+                }
+                prefix = prefix_items.len();
+            }
+            if let Some(items) = schema.get("items") {
+                for (index, item) in list.iter().enumerate().skip(prefix) {
+                    self.check(
+                        items,
+                        item,
+                        &format!("{instance_path}/{index}"),
+                        &format!("{schema_path}/items"),
+                        depth,
+                        violations,
+                    );
+                }
+            }
+        }
+        if let JsonValue::Object(map) = value {
+            let properties = match schema.get("properties") {
+                Some(JsonValue::Object(properties)) => Some(properties),
+                _ => None,
+            };
+            for (key, item) in map {
+                let item_path = format!("{instance_path}/{}", escape(key));
+                if let Some(property) = properties.and_then(|properties| properties.get(key)) {
+                    self.check(
+                        property,
+                        item,
+                        &item_path,
+                        &format!("{schema_path}/properties/{}", escape(key)),
+                        depth,
+                        violations,
+                    );
+                } else if let Some(additional) = schema.get("additionalProperties") {
+                    self.check(
+                        additional,
+                        item,
+                        &item_path,
+                        &format!("{schema_path}/additionalProperties"),
+                        depth,
+                        violations,
+                    );
+                }
+            }
+        }
+        if let Some(JsonValue::Array(schemas)) = schema.get("allOf") {
+            for (index, sub_schema) in schemas.iter().enumerate() {
+                self.check(
+                    sub_schema,
+                    value,
+                    instance_path,
+                    &format!("{schema_path}/allOf/{index}"),
+                    depth,
+                    violations,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::regex_cache::RegexCache;
+
+    fn check(schema: &str, value: &str) -> Vec<String> {
+        let schema = JsonValue::from_str(schema).unwrap();
+        let value = JsonValue::from_str(value).unwrap();
+        validate(&schema, &value, &RegexCache::new(0))
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn valid_value_has_no_violations() {
+        let schema = r#"{"type": "object", "required": ["id"], "properties": {"id": {"type": "integer"}, "tags": {"type": "array", "items": {"type": "string"}}}}"#;
+
+        assert!(check(schema, r#"{"id": 1.0, "tags": ["a"], "other": null}"#).is_empty());
+    }
+
+    #[test]
+    fn type_can_be_a_list() {
+        let schema = r#"{"type": ["string", "null"]}"#;
+
+        assert!(check(schema, "null").is_empty());
+        assert_eq!(
+            check(schema, "1.5"),
+            vec!["should be one of \"string\", \"null\", got number (at `#/type`)"]
+        );
+    }
+
+    #[test]
+    fn nested_violations_have_paths() {
+        let schema = r#"{"properties": {"a/b": {"items": {"maxLength": 2}}}, "additionalProperties": false}"#;
+
+        assert_eq!(
+            check(schema, r#"{"a/b": ["ok", "long"], "c": 1}"#),
+            vec![
+                "`/a~1b/1` should have at most 2 characters (at `#/properties/a~1b/items/maxLength`)",
+                "`/c` is not allowed (at `#/additionalProperties`)",
+            ]
+        );
+    }
+
+    #[test]
+    fn prefix_items_and_items() {
+        let schema = r#"{"prefixItems": [{"type": "string"}], "items": {"type": "number"}, "uniqueItems": true}"#;
+
+        assert!(check(schema, r#"["a", 1, 2]"#).is_empty());
+        assert_eq!(
+            check(schema, r#"[1, 1]"#),
+            vec![
+                "should not have duplicate items (at `#/uniqueItems`)",
+                "`/0` should be string, got number (at `#/prefixItems/0/type`)",
+            ]
+        );
+    }
+
+    #[test]
+    fn combinations() {
+        let schema = r#"{"anyOf": [{"type": "string"}, {"minimum": 10}], "oneOf": [{"type": "number"}, {"multipleOf": 5}], "not": {"const": 20}}"#;
+
+        assert!(check(schema, "12").is_empty());
+        assert_eq!(
+            check(schema, "20"),
+            vec![
+                "should match exactly one schema, matched 2 (at `#/oneOf`)",
+                "should not match the schema (at `#/not`)",
+            ]
+        );
+        assert_eq!(
+            check(schema, "3"),
+            vec!["should match at least one schema (at `#/anyOf`)"]
+        );
+    }
+
+    #[test]
+    fn enum_and_pattern() {
+        let schema = r#"{"enum": ["info", "warn", 3], "pattern": "^[a-z]+$"}"#;
+
+        assert!(check(schema, "3").is_empty());
+        assert_eq!(
+            check(schema, r#""Error""#),
+            vec![
+                "is not one of the allowed values (at `#/enum`)",
+                "should match `^[a-z]+$` (at `#/pattern`)",
+            ]
+        );
+    }
+
+    #[test]
+    fn multiple_of_decimal_step_is_exact() {
+        let schema = r#"{"multipleOf": 0.1}"#;
+
+        assert!(check(schema, "0.3").is_empty());
+        assert!(check(schema, "12").is_empty());
+        assert!(check(schema, r#""text""#).is_empty());
+        assert_eq!(
+            check(schema, "0.35"),
+            vec!["should be a multiple of 0.1 (at `#/multipleOf`)"]
+        );
+        assert_eq!(
+            check(r#"{"multipleOf": 3}"#, "-7"),
+            vec!["should be a multiple of 3 (at `#/multipleOf`)"]
+        );
+    }
+
+    #[test]
+    fn references_are_resolved_within_the_schema() {
+        let schema = r##"{"$defs": {"node": {"type": "object", "properties": {"value": {"type": "number"}, "next": {"$ref": "#/$defs/node"}}}}, "$ref": "#/$defs/node"}"##;
+
+        assert!(check(schema, r#"{"value": 1, "next": {"value": 2}}"#).is_empty());
+        assert_eq!(
+            check(schema, r#"{"value": 1, "next": {"value": "2"}}"#),
+            vec![
+                "`/next/value` should be number, got string (at `#/$ref/properties/next/$ref/properties/value/type`)"
+            ]
+        );
+        assert_eq!(
+            check(r##"{"$ref": "#/$defs/missing"}"##, "1"),
+            vec!["can not resolve reference `#/$defs/missing` (at `#/$ref`)"]
+        );
+        let loop_violations = check(r##"{"$ref": "#"}"##, "1");
+        assert_eq!(loop_violations.len(), 1);
+        assert!(loop_violations[0].starts_with("has too many nested references"));
+    }
+
+    #[test]
+    fn references_can_not_fan_out_forever() {
+        let loop_violations = check(r##"{"anyOf": [{"$ref": "#"}, {"$ref": "#"}]}"##, "1");
+        assert_eq!(
+            loop_violations,
+            vec!["should match at least one schema (at `#/anyOf`)"]
+        );
+
+        let mut definitions: Vec<String> = (0..40)
+            .map(|index| {
+                let next = format!("{{\"$ref\": \"#/$defs/d{}\"}}", index + 1);
+                format!("\"d{index}\": {{\"allOf\": [{next}, {next}]}}")
+            })
+            .collect();
+        definitions.push("\"d40\": {\"type\": \"string\"}".to_string());
+        let schema = format!(
+            "{{\"$defs\": {{{}}}, \"$ref\": \"#/$defs/d0\"}}",
+            definitions.join(", ")
+        );
+        let violations = check(&schema, "1");
+        assert_eq!(
+            violations.last().map(String::as_str),
+            Some("needs too many checks to validate (at `#`)")
+        );
+    }
+}
@@ -2,64 +2,97 @@ mod additional_help;
 #[cfg(feature = "create-docs")]
 mod build_docs;
 mod const_getter;
+mod dead_letter;
 mod duplication_remover;
+mod explain;
 mod extractor;
 mod filter;
+mod follow;
 mod functions;
 mod functions_definitions;
 mod grouper;
 mod input_context_extractor;
+mod input_files;
 mod json_parser;
+mod json_schema;
 mod json_value;
 mod limits;
 mod merger;
 mod output_style;
+mod partitioner;
 mod pre_sets;
 mod processor;
 mod reader;
 mod regex_cache;
+mod repl;
+mod schema;
 mod selection;
+mod selection_diagnostic;
 mod selection_extractor;
-#[cfg(feature = "create-docs")]
 mod selection_help;
+mod sketches;
 mod sorters;
 mod splitter;
+mod stats;
+mod stream_splitter;
+mod strict;
+mod tee;
+mod template;
+mod validator;
 mod variables_extractor;
 
-use additional_help::display_additional_help;
+use additional_help::{
+    HelpOutput, display_additional_help, display_functions_list, display_functions_search,
+};
 use clap::Parser;
+use dead_letter::DeadLetter;
 use duplication_remover::Uniqueness;
+use explain::Explanation;
 use filter::Filter;
 use grouper::Grouper;
-use json_parser::JsonParserError;
+use input_files::{InputFiles, InputFilesError, Symlinks};
+use json_parser::{JsonParserError, RecoveryStrategy, SkippedInput};
+use json_schema::SchemaError;
 use json_value::JsonValue;
 use limits::Limiter;
 use merger::Merger;
 use output_style::OutputOptions;
 use output_style::OutputStyleValidationError;
+use partitioner::{InvalidPartition, Partitioner};
 use pre_sets::PreSetCollection;
 use pre_sets::PreSetParserError;
 use processor::ProcessDecision;
-use processor::{Context, Process, ProcessError, Titles};
+use processor::{Context, InputFile, InputSource, Process, ProcessError, Titles};
 use regex_cache::RegexCache;
+use repl::{Repl, ReplError, SampleCollector};
+use schema::SchemaFormat;
+use selection::Get;
 use selection::Selection;
 use selection::SelectionParseError;
+use selection_diagnostic::SelectionDiagnostic;
 use sorters::Sorter;
 use sorters::SorterParserError;
 use splitter::Splitter;
-use std::cell::RefCell;
+use stats::Stats;
+use std::cell::{Cell, RefCell};
+use std::fmt::Display;
 use std::fmt::Error as FormatError;
-use std::fs::read_dir;
 use std::io::Error as IoError;
+use std::io::IsTerminal;
 use std::io::Read;
 use std::path::PathBuf;
 use std::rc::Rc;
 use std::str::FromStr;
+use std::time::UNIX_EPOCH;
+use stream_splitter::{StreamSplitter, StreamSplitterParseError};
+use strict::{StrictError, StrictErrors};
+use tee::{TeeParserError, create_tee_process, explain_tee};
 use thiserror::Error;
+use validator::{InvalidInput, SchemaValidation};
 
 use crate::additional_help::create_possible_values;
 use crate::json_parser::JsonParser;
-use crate::reader::{Reader, from_file, from_std_in};
+use crate::reader::{Location, Reader, from_file, from_followed_file, from_std_in};
 
 /// An AWK like toold for JSON input.
 ///
@@ -71,14 +104,81 @@ pub struct Cli {
     /// Input files.
     ///
     /// If omitted the standard in will be used.
-    /// If any of the files is a directory, all it's files will be used.
+    /// If any of the files is a directory, all it's files will be used (in sorted order).
+    /// Glob patterns (like `logs/**/*.json`) will be expanded.
     files: Vec<PathBuf>,
 
+    /// Only read the files within the input directories (or glob patterns) that match one of those glob patterns.
+    ///
+    /// For example: `--include=*.json`.
+    #[arg(long)]
+    include: Vec<String>,
+
+    /// Do not read the files and directories within the input directories (or glob patterns) that match one of
+    /// those glob patterns.
+    ///
+    /// For example: `--exclude=**/tmp/**`.
+    #[arg(long)]
+    exclude: Vec<String>,
+
+    /// How deep to go into the input directories.
+    ///
+    /// With 1, only the files that are directly within the input directories will be read. For glob patterns, the
+    /// depth is counted from the directory before the first wildcard.
+    #[arg(long)]
+    max_depth: Option<usize>,
+
+    /// Keep reading the last input file as it grows (like `tail -f`).
+    ///
+    /// Once the end of the last file is reached, wait for new values to be appended to it, and output them as they
+    /// arrive. If the file is truncated or replaced (for example by log rotation), it is read again from the start.
+    /// The output (including the `--tee` and the `--partition-by` files) is flushed after each input.
+    /// Can not be used with `--sort-by` or `--group-by`, as those only produce output at the end of the input.
+    #[arg(long)]
+    follow: bool,
+
+    /// What to do with symbolic links within the input directories.
+    #[arg(long, default_value_t = Symlinks::Follow)]
+    #[clap(value_enum)]
+    symlinks: Symlinks,
+
     /// What to do on error
     #[arg(long, default_value_t = OnError::Ignore)]
     #[clap(value_enum)]
     on_error: OnError,
 
+    /// Where to resume reading after an invalid input.
+    ///
+    /// With `line`, each value must be in a single line, so a truncated line will not swallow the
+    /// following lines. With `record-separator`, the input is a JSON text sequence (RFC 7464) and an invalid record
+    /// is skipped up to the next record separator. When skipping, the number of skipped bytes (from the start of
+    /// the invalid value) will be reported.
+    /// The record separators are always skipped between values, and always end an incomplete value.
+    #[arg(long, default_value_t = RecoveryStrategy::Character)]
+    #[clap(value_enum)]
+    recovery: RecoveryStrategy,
+
+    /// Report why a selection produced nothing.
+    ///
+    /// By default, a selection that can not be calculated (for example, a missing key, a function that got an
+    /// argument of the wrong type or an invalid regular expression) will produce nothing.
+    /// In strict mode, the reason will be reported with the location of the input, using the `--on-error` option.
+    #[arg(long)]
+    strict: bool,
+
+    /// Write the inputs that could not be processed into a file.
+    ///
+    /// Each line in the file will be a JSON object with the raw text of the input (if available), the file,
+    /// line and character in which it started and the error messages. Inputs that can not be parsed, inputs
+    /// that failed a selection in strict mode and inputs that failed `--validate` will be written to that file.
+    /// With the `character` recovery, consecutive invalid characters are written as a single input.
+    #[arg(long)]
+    dead_letter: Option<PathBuf>,
+
+    /// Abort once the number of inputs with errors passed this number.
+    #[arg(long)]
+    max_errors: Option<usize>,
+
     /// What to output.
     ///
     /// Can be multiple selection. The expected format is `<selection>[=name]`.
@@ -98,6 +198,18 @@ pub struct Cli {
     #[arg(long, short, visible_alias = "where")]
     filter: Option<String>,
 
+    /// Validate the input against a JSON schema file.
+    ///
+    /// Inputs that do not match the schema will be ignored, and each violation will be reported with the location
+    /// of the input, using the `--on-error` option (and `--dead-letter` if set).
+    /// The common draft 2020-12 keywords are supported (like `type`, `properties`, `required`, `items`, `enum`,
+    /// `pattern`, `minimum`, `oneOf` and `$ref` within the schema). This will run after `--split-by` and before
+    /// the filter.
+    ///
+    /// For example: `--validate=schema.json`.
+    #[arg(long)]
+    validate: Option<PathBuf>,
+
     /// Split the input by.
     ///
     /// The expected format is `<selection>`.
@@ -109,6 +221,16 @@ pub struct Cli {
     #[arg(long, short, visible_alias = "split-by")]
     break_by: Option<String>,
 
+    /// Split an array within the input while it is being read, without loading the whole input into memory.
+    ///
+    /// The expected format is a path of keys, like `.results` or `.data.items` (use `.` if the input is an array).
+    /// Each element of the array will be processed as a separate input. Inputs without an array in that path
+    /// will be ignored. This will run before `--split-by`.
+    ///
+    /// For example: `--stream-split-by=.results`.
+    #[arg(long)]
+    stream_split_by: Option<String>,
+
     /// Group the output by.
     ///
     /// Be careful, the grouping is done in memory.
@@ -121,6 +243,31 @@ pub struct Cli {
     #[arg(long, short, visible_alias = "combine", visible_alias = "merge")]
     group_by: Option<Option<String>>,
 
+    /// Print a single summary with statistics of the selected fields, once all the inputs were read.
+    ///
+    /// The expected format is `<selection>[=name]`. Allow multiple fields.
+    /// For each field, the summary include the count of values, the count of nulls (or missing values), an estimate of
+    /// the number of distinct values and the most frequent values. For numbers it also include the min, max, mean,
+    /// standard deviation and the 50th, 90th and 99th percentiles.
+    /// The statistics are computed in constant memory, so the distinct count, the percentiles (within 1%) and the
+    /// counts of the most frequent values are estimates.
+    /// See selection additional help for available selections format.
+    ///
+    /// For example: `--stats=.latency --stats=.status`.
+    #[arg(long, conflicts_with = "group_by")]
+    stats: Vec<String>,
+
+    /// Describe the structure of the output instead of printing it, once all the inputs were read.
+    ///
+    /// For each path, the description include the observed types, if the path is missing from some records, the
+    /// range of the string lengths and of the numbers, the possible values (if there are only a few of them) and the
+    /// types of the arrays elements.
+    /// The result can be a JSON Schema (draft 2020-12) document (the default) or a short description of each path.
+    ///
+    /// For example: `--infer-schema` or `--infer-schema=tree`.
+    #[arg(long, value_enum, conflicts_with_all = ["group_by", "stats"])]
+    infer_schema: Option<Option<SchemaFormat>>,
+
     /// How to order the output. Allow muttiploe sorting.
     ///
     /// Be careful, the sorting is done in memory.
@@ -154,6 +301,43 @@ pub struct Cli {
     )]
     additional_help: Option<String>,
 
+    /// List all the available functions.
+    ///
+    /// Display the list of the functions, with their arguments and a short description.
+    #[arg(long)]
+    list_functions: bool,
+
+    /// Search for a function.
+    ///
+    /// Display the functions that have the text in their name, aliases or description.
+    ///
+    /// For example: `--search-functions=regular`.
+    #[arg(long)]
+    search_functions: Option<String>,
+
+    /// Start an interactive session to build selections.
+    ///
+    /// The inputs (after `--split-by`, `--skip` and `--take`) will be loaded as samples, and every selection that
+    /// will be typed will be displayed for all the samples, using the output style. If the samples are read from
+    /// the standard input, the commands are read from the terminal.
+    /// Type `\help` within the session to see the available commands.
+    #[arg(long)]
+    repl: bool,
+
+    /// Print the pipeline that will be used to process the input and the parsed selections, without running it.
+    ///
+    /// The steps are printed in the order in which each input will go through them, and each selection
+    /// is printed as a tree of functions, extractors, variables and constants.
+    #[arg(long)]
+    explain: bool,
+
+    /// Do not use a pager for the additional help.
+    ///
+    /// By default, when the output is a terminal, the additional help is displayed using the `PAGER` environment
+    /// variable (or `less` if it is not set).
+    #[arg(long)]
+    no_pager: bool,
+
     /// Avoid posting the same output more than once.
     ///
     /// Be careful, the data is kept in memory.
@@ -185,6 +369,41 @@ pub struct Cli {
     #[arg(long)]
     only_objects_and_arrays: bool,
 
+    /// Write the output into a file per partition.
+    ///
+    /// The expected format is `<selection>`.
+    /// Each output will be written into a file in the `--output-dir` directory, named after the value of the selection
+    /// and the output style (for example `2024-05-01.ndjson`, `2024-05-01.csv` or `2024-05-01.txt`).
+    /// Numbers and booleans are used as they are. Any other value, or a string that can not be used as a file name,
+    /// is an error (and the output will be sent to `--dead-letter` if set).
+    /// See selection additional help for available selections format.
+    ///
+    /// For example: `--partition-by=(head .timestamp 10) --output-dir=out`.
+    #[arg(long, requires = "output_dir")]
+    partition_by: Option<String>,
+
+    /// The directory to write the partitions files into (see `--partition-by`).
+    ///
+    /// The directory will be created if needed. Existing partitions files will be overwritten.
+    #[arg(long, requires = "partition_by")]
+    output_dir: Option<PathBuf>,
+
+    /// Maximal number of partitions files that can be open at the same time (see `--partition-by`).
+    ///
+    /// Once the limit is reached, the least recently used file will be closed, and reopened when needed.
+    #[arg(long, default_value_t = 64)]
+    max_open_files: usize,
+
+    /// Write the output into an additional file. Can be set more than once.
+    ///
+    /// The expected format is `<output-style>:<file>`, where the output style is one of `json`, `csv` or `text`.
+    /// The additional outputs use the main output options (like `--row-separator`, the JSON options or the
+    /// text options) that apply to their style, and will get the same output as the main one.
+    ///
+    /// For example: `--tee csv:summary.csv --tee json:full.ndjson`.
+    #[arg(long)]
+    tee: Vec<String>,
+
     #[command(flatten)]
     output_options: OutputOptions,
 }
@@ -192,7 +411,7 @@ pub struct Cli {
 #[derive(clap::ValueEnum, Debug, Clone, PartialEq)]
 #[clap(rename_all = "kebab_case")]
 enum OnError {
-    /// Do nothing with the error.
+    /// Do nothing with the error (the exit code will indicate errors only if `--dead-letter` is used).
     Ignore,
     /// Exit the process on the first error.
     Panic,
@@ -230,6 +449,13 @@ struct Master<R: Read> {
     stderr: Rc<RefCell<dyn std::io::Write + Send>>,
     stdin: Box<dyn Fn() -> R>,
     regular_expression_cache: RegexCache,
+    strict_errors: Option<StrictErrors>,
+    dead_letter: Option<RefCell<DeadLetter>>,
+    schema_validation: Option<SchemaValidation>,
+    stream_splitter: Option<StreamSplitter>,
+    partitioner: Option<Partitioner>,
+    raw_input: Cell<bool>,
+    error_count: Cell<usize>,
 }
 
 impl<S: Read> Master<S> {
@@ -240,32 +466,120 @@ impl<S: Read> Master<S> {
         stdin: Box<dyn Fn() -> S>,
     ) -> Self {
         let regular_expression_cache = RegexCache::new(cli.regular_expression_cache_size);
+        let strict_errors = cli.strict.then(StrictErrors::default);
         Master {
             cli,
             stdout,
             stderr,
             stdin,
             regular_expression_cache,
+            strict_errors,
+            dead_letter: None,
+            schema_validation: None,
+            stream_splitter: None,
+            partitioner: None,
+            raw_input: Cell::new(false),
+            error_count: Cell::new(0),
         }
     }
 
-    pub fn go(&self) -> Result<()> {
+    pub fn go(mut self) -> Result<()> {
+        if let Some(dead_letter) = &self.cli.dead_letter {
+            self.dead_letter = Some(RefCell::new(DeadLetter::create(dead_letter)?));
+        }
+        if let Some(schema) = &self.cli.validate {
+            self.schema_validation = Some(SchemaValidation::load(schema)?);
+        }
+        if let Some(stream_split_by) = &self.cli.stream_split_by {
+            self.stream_splitter = Some(StreamSplitter::from_str(stream_split_by)?);
+        }
+        if let (Some(partition_by), Some(_)) = (&self.cli.partition_by, &self.cli.output_dir) {
+            self.partitioner = Some(parse::<Partitioner>(partition_by)?);
+        }
+        let result = self.run();
+        if let Some(dead_letter) = &self.dead_letter {
+            dead_letter.borrow_mut().flush()?;
+        }
+        result?;
+        let errors = self.error_count.get();
+        if errors > 0 && (self.cli.on_error != OnError::Ignore || self.dead_letter.is_some()) {
+            return Err(MainError::ErrorsOccurred(errors));
+        }
+        Ok(())
+    }
+
+    fn run(&self) -> Result<()> {
+        let help_output = HelpOutput::new(
+            self.stdout.clone(),
+            !self.cli.no_pager && std::io::stdout().is_terminal(),
+        );
         if let Some(help_type) = &self.cli.additional_help {
-            display_additional_help(help_type);
+            display_additional_help(help_type, &help_output)?;
+            return Ok(());
+        }
+        if self.cli.list_functions {
+            display_functions_list(&help_output)?;
             return Ok(());
         }
-        let mut process = self.cli.output_options.get_processor(self.stdout.clone())?;
+        if let Some(term) = &self.cli.search_functions {
+            display_functions_search(term, &help_output)?;
+            return Ok(());
+        }
+        if self.cli.repl {
+            return self.repl();
+        }
+        if self.cli.explain {
+            return self.explain();
+        }
+        if self.cli.follow
+            && (!self.cli.sort_by.is_empty()
+                || self.cli.group_by.is_some()
+                || !self.cli.stats.is_empty()
+                || self.cli.infer_schema.is_some())
+        {
+            return Err(MainError::FollowWithoutStreaming);
+        }
+        let mut raw_input = self.cli.set.uses_raw_input()?;
+        let mut process = if let (Some(partitioner), Some(output_dir)) =
+            (&self.partitioner, &self.cli.output_dir)
+        {
+            raw_input |= partitioner.uses_raw_input();
+            partitioner.create_process(
+                output_dir,
+                &self.cli.output_options,
+                self.cli.max_open_files,
+                self.cli.follow,
+            )?
+        } else {
+            self.cli.output_options.get_processor(self.stdout.clone())?
+        };
+        process = create_tee_process(
+            &self.cli.tee,
+            &self.cli.output_options,
+            self.cli.follow,
+            process,
+        )?;
         if let Some(group_by) = &self.cli.group_by {
             if let Some(group_by) = group_by {
-                let group_by = Grouper::from_str(group_by)?;
+                let group_by = parse::<Grouper>(group_by)?;
+                raw_input |= group_by.uses_raw_input();
                 process = group_by.create_process(process);
             } else {
                 process = Merger::create_process(process);
             }
         }
+        if !self.cli.stats.is_empty() {
+            let stats = self.stats()?;
+            raw_input |= stats.uses_raw_input();
+            process = stats.create_process(process);
+        }
+        if let Some(format) = &self.cli.infer_schema {
+            process = format.unwrap_or_default().create_process(process);
+        }
         process = Limiter::create_process(self.cli.skip, self.cli.take, process);
         for sorter in &self.cli.sort_by {
-            let sorter = Sorter::from_str(sorter)?;
+            let sorter = parse_sorter(sorter)?;
+            raw_input |= sorter.uses_raw_input();
             let max_size = self.cli.take.map(|take| (self.cli.skip + take) as usize);
             process = sorter.create_processor(process, max_size);
         }
@@ -273,57 +587,245 @@ impl<S: Read> Master<S> {
             process = Uniqueness::create_process(process);
         }
         for selection in self.cli.choose.iter().rev() {
-            let selection = Selection::from_str(selection)?;
+            let selection = parse::<Selection>(selection)?;
+            raw_input |= selection.uses_raw_input();
             process = selection.create_process(process);
         }
         if let Some(filter) = &self.cli.filter {
-            let filter = Filter::from_str(filter)?;
+            let filter = parse::<Filter>(filter)?;
+            raw_input |= filter.uses_raw_input();
             process = filter.create_process(process);
         }
+        if let Some(schema_validation) = &self.schema_validation {
+            process = schema_validation.create_process(process);
+        }
         if let Some(splitter) = &self.cli.break_by {
-            let splitter = Splitter::from_str(splitter)?;
+            let splitter = parse::<Splitter>(splitter)?;
+            raw_input |= splitter.uses_raw_input();
             process = splitter.create_process(process);
         }
         process = self.cli.set.create_process(process)?;
         process.start(Titles::default())?;
 
+        self.raw_input.set(raw_input);
+        self.read_all(process.as_mut())?;
+        process.complete()?;
+        if let Some(strict_errors) = &self.strict_errors {
+            for e in strict_errors.take() {
+                let location = e.location().cloned();
+                self.record_failed(location.as_ref(), None, vec![e])?;
+            }
+        }
+        if let Some(partitioner) = &self.partitioner {
+            for e in partitioner.take() {
+                let location = e.location().cloned();
+                self.record_failed(location.as_ref(), None, vec![e])?;
+            }
+        }
+        Ok(())
+    }
+
+    fn explain(&self) -> Result<()> {
+        let input = if self.cli.files.is_empty() {
+            "read from stdin".to_string()
+        } else {
+            let files: Vec<_> = self
+                .cli
+                .files
+                .iter()
+                .map(|file| format!("`{}`", file.display()))
+                .collect();
+            format!("read from {}", files.join(", "))
+        };
+        let mut steps = vec![Explanation::new(input)];
+        if let Some(stream_split_by) = &self.cli.stream_split_by {
+            steps.push(StreamSplitter::from_str(stream_split_by)?.explain());
+        }
+        if !self.cli.set.is_empty() {
+            steps.push(self.cli.set.explain()?);
+        }
+        if let Some(splitter) = &self.cli.break_by {
+            steps.push(parse::<Splitter>(splitter)?.explain());
+        }
+        if let Some(schema_validation) = &self.schema_validation {
+            steps.push(schema_validation.explain());
+        }
+        if let Some(filter) = &self.cli.filter {
+            steps.push(parse::<Filter>(filter)?.explain());
+        }
+        for selection in &self.cli.choose {
+            steps.push(parse::<Selection>(selection)?.explain());
+        }
+        if self.cli.unique {
+            steps.push(Explanation::new("unique"));
+        }
+        for sorter in self.cli.sort_by.iter().rev() {
+            steps.push(parse_sorter(sorter)?.explain());
+        }
+        let mut limits = vec![];
+        if self.cli.skip > 0 {
+            limits.push(format!("skip {}", self.cli.skip));
+        }
+        if let Some(take) = self.cli.take {
+            limits.push(format!("take {take}"));
+        }
+        if !limits.is_empty() {
+            steps.push(Explanation::new(limits.join(", ")));
+        }
+        if let Some(group_by) = &self.cli.group_by {
+            if let Some(group_by) = group_by {
+                steps.push(parse::<Grouper>(group_by)?.explain());
+            } else {
+                steps.push(Explanation::new("merge"));
+            }
+        }
+        if !self.cli.stats.is_empty() {
+            steps.push(self.stats()?.explain());
+        }
+        if let Some(format) = &self.cli.infer_schema {
+            steps.push(format.unwrap_or_default().explain());
+        }
+        steps.extend(explain_tee(&self.cli.tee, &self.cli.output_options)?);
+        self.cli.output_options.validate()?;
+        if let (Some(partitioner), Some(output_dir)) = (&self.partitioner, &self.cli.output_dir) {
+            steps.push(
+                partitioner
+                    .explain(output_dir)
+                    .with_child(self.cli.output_options.explain()),
+            );
+        } else {
+            steps.push(self.cli.output_options.explain());
+        }
+        let explanation = Explanation::new("pipeline").with_children(steps);
+        write!(self.stdout.borrow_mut(), "{explanation}")?;
+        Ok(())
+    }
+
+    fn stats(&self) -> Result<Stats> {
+        let selections = self
+            .cli
+            .stats
+            .iter()
+            .map(|selection| parse::<Selection>(selection))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Stats::new(selections))
+    }
+
+    fn repl(&self) -> Result<()> {
+        let samples = Rc::new(RefCell::new(Vec::new()));
+        let mut process = SampleCollector::create_process(&samples);
+        process = Limiter::create_process(self.cli.skip, self.cli.take, process);
+        if let Some(splitter) = &self.cli.break_by {
+            let splitter = parse::<Splitter>(splitter)?;
+            process = splitter.create_process(process);
+        }
+        process.start(Titles::default())?;
+        self.read_all(process.as_mut())?;
+        process.complete()?;
+
+        let mut repl = Repl::new(
+            samples.take(),
+            self.cli.output_options.clone(),
+            self.cli.set.clone(),
+            self.cli.filter.clone(),
+            self.stdout.clone(),
+            self.stderr.clone(),
+        );
+        repl.run()?;
+        Ok(())
+    }
+
+    fn read_all(&self, process: &mut dyn Process) -> Result<()> {
         let mut index = 0;
         if self.cli.files.is_empty() {
             let mut reader = from_std_in((self.stdin)());
-            self.read_input(&mut reader, &mut index, process.as_mut())?;
+            self.read_input(&mut reader, &mut index, None, process)?;
         } else {
-            for file in self.cli.files.clone() {
-                self.read_file(&file, &mut index, process.as_mut())?;
+            let input_files = InputFiles::new(
+                &self.cli.include,
+                &self.cli.exclude,
+                self.cli.max_depth,
+                self.cli.symlinks.clone(),
+            )?;
+            let files = input_files.list(&self.cli.files)?;
+            for (file_index, file) in files.iter().enumerate() {
+                let follow = self.cli.follow && file_index + 1 == files.len();
+                self.read_file(file, &mut index, file_index as u64, follow, process)?;
             }
         }
-        process.complete()?;
         Ok(())
     }
 
-    fn read_file(&self, file: &PathBuf, index: &mut u64, process: &mut dyn Process) -> Result<()> {
-        assert!(file.exists(), "File {file:?} not exists");
-        if file.is_dir() {
-            for entry in read_dir(file)? {
-                let path = entry?.path();
-                self.read_file(&path, index, process)?;
-            }
+    fn read_file(
+        &self,
+        file: &PathBuf,
+        index: &mut u64,
+        file_index: u64,
+        follow: bool,
+        process: &mut dyn Process,
+    ) -> Result<()> {
+        let metadata = file.metadata()?;
+        let input_file = InputFile {
+            path: std::path::absolute(file)?,
+            size: metadata.len(),
+            modified: metadata
+                .modified()
+                .ok()
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map(|duration| duration.as_secs_f64()),
+            index: file_index,
+        };
+        let input_file = Some(Rc::new(input_file));
+        if follow {
+            let mut reader = from_followed_file(file)?;
+            self.read_input(&mut reader, index, input_file, process)
         } else {
             let mut reader = from_file(file)?;
-            self.read_input(&mut reader, index, process)?;
+            self.read_input(&mut reader, index, input_file, process)
         }
-        Ok(())
     }
+
     fn read_input<R: Read>(
         &self,
         reader: &mut Reader<R>,
         index: &mut u64,
+        input_file: Option<Rc<InputFile>>,
         process: &mut dyn Process,
     ) -> Result<()> {
         let mut in_file_index: u64 = 0;
+        // Keeping the original bytes is costly, so it is done only when they are needed.
+        let capture = self.dead_letter.is_some() || self.cli.repl || self.raw_input.get();
+        if capture {
+            reader.start_capture();
+        }
+        let mut stream_splitter = self.stream_splitter.clone();
+        let mut invalid_span: Option<InvalidSpan> = None;
         loop {
+            let sought = match &mut stream_splitter {
+                Some(stream_splitter) => stream_splitter.seek(reader),
+                None => Ok(true),
+            };
             let started = reader.where_am_i();
-            match reader.next_json_value() {
+            reader.eat_record_separators()?;
+            let skipped_before = reader.take_capture();
+            let start_offset = reader.byte_offset();
+            let value = match sought {
+                Ok(true) if self.cli.recovery == RecoveryStrategy::Line => {
+                    reader.next_json_value_in_line()
+                }
+                Ok(true) => reader.next_json_value(),
+                Ok(false) => Ok(None),
+                Err(e) => {
+                    if let Some(stream_splitter) = &mut stream_splitter {
+                        stream_splitter.reset();
+                    }
+                    Err(e)
+                }
+            };
+            let mut raw: Option<Rc<[u8]>> = capture.then(|| reader.take_capture().into());
+            match value {
                 Ok(Some(val)) => {
+                    self.report_invalid_span(invalid_span.take())?;
                     if self.cli.only_objects_and_arrays {
                         match val {
                             JsonValue::Object(_) | JsonValue::Array(_) => {}
@@ -333,15 +835,48 @@ impl<S: Read> Master<S> {
                         }
                     }
                     let ended = reader.where_am_i();
-                    let context = Context::new_with_input(
+                    let source = InputSource {
+                        raw: raw.clone(),
+                        byte_offsets: start_offset..reader.byte_offset(),
+                    };
+                    let mut context = Context::new_with_input(
                         val,
-                        started,
+                        started.clone(),
                         ended,
                         in_file_index,
                         *index,
                         &self.regular_expression_cache,
-                    );
-                    match process.process(context)? {
+                    )
+                    .with_source(source);
+                    if let Some(input_file) = &input_file {
+                        context = context.with_input_file(input_file);
+                    }
+                    if let Some(strict_errors) = &self.strict_errors {
+                        context = context.with_strict_errors(strict_errors);
+                    }
+                    let decision = process.process(context)?;
+                    if self.cli.follow {
+                        self.stdout.borrow_mut().flush()?;
+                    }
+                    if let Some(strict_errors) = &self.strict_errors {
+                        let errors = strict_errors.take();
+                        if !errors.is_empty() {
+                            self.record_failed(Some(&started), raw.as_deref(), errors)?;
+                        }
+                    }
+                    if let Some(schema_validation) = &self.schema_validation {
+                        let errors = schema_validation.take(&started);
+                        if !errors.is_empty() {
+                            self.record_failed(Some(&started), raw.as_deref(), errors)?;
+                        }
+                    }
+                    if let Some(partitioner) = &self.partitioner {
+                        let errors = partitioner.take();
+                        if !errors.is_empty() {
+                            self.record_failed(Some(&started), raw.as_deref(), errors)?;
+                        }
+                    }
+                    match decision {
                         ProcessDecision::Break => {
                             break Ok(());
                         }
@@ -352,24 +887,118 @@ impl<S: Read> Master<S> {
                     }
                 }
                 Ok(None) => {
-                    return Ok(());
+                    return self.report_invalid_span(invalid_span.take());
                 }
                 Err(e) => {
                     if !e.can_recover() {
+                        self.report_invalid_span(invalid_span.take())?;
                         return Err(e.into());
                     }
-                    match self.cli.on_error {
-                        OnError::Ignore => {}
-                        OnError::Panic => {
-                            return Err(e.into());
+                    let location = e.location().cloned();
+                    if self.cli.recovery == RecoveryStrategy::Character
+                        && self.dead_letter.is_some()
+                    {
+                        // Each invalid character is an error of its own, so they are merged to keep the whole
+                        // invalid text in a single dead letter.
+                        match &mut invalid_span {
+                            Some(span) => {
+                                span.end_offset = reader.byte_offset();
+                                if let (Some(span_raw), Some(raw)) = (&mut span.raw, &raw) {
+                                    span_raw.extend_from_slice(&skipped_before);
+                                    span_raw.extend_from_slice(raw);
+                                }
+                            }
+                            None => {
+                                invalid_span = Some(InvalidSpan {
+                                    error: e,
+                                    start_offset,
+                                    end_offset: reader.byte_offset(),
+                                    raw: raw.as_deref().map(<[u8]>::to_vec),
+                                });
+                            }
+                        }
+                    } else if self.cli.recovery == RecoveryStrategy::Character {
+                        self.record_failed(location.as_ref(), raw.as_deref(), vec![e])?;
+                    } else {
+                        // The bytes of the invalid value that were read before the error are skipped as well.
+                        let consumed = (reader.byte_offset() - start_offset) as usize;
+                        let skipped = consumed + reader.recover(&self.cli.recovery)?;
+                        if let Some(captured) = &mut raw {
+                            *captured = [captured.as_ref(), &reader.take_capture()].concat().into();
                         }
-                        OnError::Stdout => writeln!(self.stdout.borrow_mut(), "error:{e}")?,
-                        OnError::Stderr => writeln!(self.stderr.borrow_mut(), "error:{e}")?,
+                        let error = SkippedInput { error: e, skipped };
+                        self.record_failed(location.as_ref(), raw.as_deref(), vec![error])?;
                     }
                 }
             };
         }
     }
+
+    fn report_invalid_span(&self, span: Option<InvalidSpan>) -> Result<()> {
+        let Some(span) = span else {
+            return Ok(());
+        };
+        let location = span.error.location().cloned();
+        let error = SkippedInput {
+            error: span.error,
+            skipped: (span.end_offset - span.start_offset) as usize,
+        };
+        self.record_failed(location.as_ref(), span.raw.as_deref(), vec![error])
+    }
+
+    fn on_error<E: Display + Into<MainError>>(&self, e: E) -> Result<()> {
+        match self.cli.on_error {
+            OnError::Ignore => {}
+            OnError::Panic => {
+                return Err(e.into());
+            }
+            OnError::Stdout => writeln!(self.stdout.borrow_mut(), "error:{e}")?,
+            OnError::Stderr => writeln!(self.stderr.borrow_mut(), "error:{e}")?,
+        }
+        Ok(())
+    }
+
+    fn record_failed<E: Display + Into<MainError>>(
+        &self,
+        location: Option<&Location>,
+        raw: Option<&[u8]>,
+        errors: Vec<E>,
+    ) -> Result<()> {
+        if let Some(dead_letter) = &self.dead_letter {
+            let messages: Vec<_> = errors.iter().map(ToString::to_string).collect();
+            dead_letter.borrow_mut().write(location, raw, &messages)?;
+        }
+        for e in errors {
+            self.on_error(e)?;
+        }
+        let count = self.error_count.get() + 1;
+        self.error_count.set(count);
+        if let Some(max_errors) = self.cli.max_errors
+            && count > max_errors
+        {
+            return Err(MainError::TooManyErrors(max_errors));
+        }
+        Ok(())
+    }
+}
+
+/// Consecutive invalid values, that are reported as a single skipped input.
+struct InvalidSpan {
+    error: JsonParserError,
+    start_offset: u64,
+    end_offset: u64,
+    raw: Option<Vec<u8>>,
+}
+
+fn parse<T: FromStr<Err = SelectionParseError>>(text: &str) -> Result<T> {
+    T::from_str(text).map_err(|e| e.with_source(text).into())
+}
+
+fn parse_sorter(text: &str) -> Result<Sorter> {
+    Sorter::from_str(text).map_err(|e| match e {
+        SorterParserError::SelectionParseError(e) => e.with_source(text).into(),
+        e => e.into(),
+    })
 }
 
 /// A result from running the go function
@@ -380,10 +1009,14 @@ pub enum MainError {
     #[error("{0}")]
     Json(#[from] JsonParserError),
     #[error("{0}")]
+    Skipped(#[from] SkippedInput),
+    #[error("{0}")]
     Format(#[from] FormatError),
     #[error("{0}")]
     SelectionParse(#[from] SelectionParseError),
     #[error("{0}")]
+    SelectionDiagnostic(#[from] SelectionDiagnostic),
+    #[error("{0}")]
     SorterParse(#[from] SorterParserError),
     #[error("{0}")]
     Io(#[from] IoError),
@@ -393,4 +1026,28 @@ pub enum MainError {
     PreSet(#[from] PreSetParserError),
     #[error("{0}")]
     OutputStyle(#[from] OutputStyleValidationError),
+    #[error("{0}")]
+    Tee(#[from] TeeParserError),
+    #[error("{0}")]
+    InputFiles(#[from] InputFilesError),
+    #[error("{0}")]
+    StreamSplitterParse(#[from] StreamSplitterParseError),
+    #[error("{0}")]
+    Repl(#[from] ReplError),
+    #[error("{0}")]
+    Strict(#[from] StrictError),
+    #[error("{0}")]
+    Schema(#[from] SchemaError),
+    #[error("{0}")]
+    InvalidInput(#[from] InvalidInput),
+    #[error("{0}")]
+    InvalidPartition(#[from] InvalidPartition),
+    #[error("Aborting, more than {0} inputs had errors")]
+    TooManyErrors(usize),
+    #[error(
+        "Can not follow the input while sorting, grouping, computing statistics or inferring a schema"
+    )]
+    FollowWithoutStreaming,
+    #[error("{0} inputs had errors")]
+    ErrorsOccurred(usize),
 }
@@ -1,20 +1,23 @@
 use std::collections::HashMap;
 use std::fmt::{Result as FmtResult, Write};
+use std::str::FromStr;
 
 use std::{cell::RefCell, rc::Rc};
 
-use clap::Args;
+use clap::{Args, ValueEnum};
 use indexmap::IndexMap;
 use thiserror::Error;
 
+use crate::explain::Explanation;
 use crate::json_value::NumberValue;
+use crate::template::{Template, TemplateError};
 use crate::{
     json_value::JsonValue,
     processor::Result as ProcessResult,
     processor::{Context, Process, ProcessDecision, ProcessError, Titles},
 };
 
-#[derive(Args, Debug)]
+#[derive(Args, Debug, Clone)]
 #[group(required = false, multiple = true)]
 pub struct OutputOptions {
     /// How to display the output
@@ -28,6 +31,15 @@ pub struct OutputOptions {
     #[arg(long, short, default_value = "\n")]
     row_separator: String,
 
+    /// Render each row through a template, instead of using an output style.
+    ///
+    /// Placeholders like `{name}` are replaced by the value with that key (or the selection with that name),
+    /// `{0}` or `{}` by the selections by their position. A placeholder can be formatted, for example
+    /// `{age:>5}` or `{balance:.2}`, see the `format` function for the details. Rows that can not be rendered
+    /// (for example, because of a missing value) are skipped.
+    #[arg(long, conflicts_with = "output_style")]
+    template: Option<String>,
+
     #[command(flatten)]
     json_options: Option<JsonOutputOptions>,
 
@@ -44,6 +56,8 @@ pub enum OutputStyle {
     Csv,
     /// Raw text output.
     Text,
+    /// JSON text sequence (RFC 7464), each record starts with a record separator (0x1E).
+    JsonSeq,
 }
 
 #[derive(Args, Debug, Clone)]
@@ -117,6 +131,8 @@ pub enum OutputStyleValidationError {
     JsonOptionsShouldNotBeHere,
     #[error("Can not define text option for non text output style")]
     TextOptionsShouldNotBeHere,
+    #[error("{0}")]
+    InvalidTemplate(#[from] TemplateError),
 }
 
 pub trait Print<W: Write> {
@@ -157,11 +173,49 @@ pub trait Print<W: Write> {
 }
 
 impl OutputOptions {
-    pub fn get_processor(
-        &self,
-        writer: Rc<RefCell<dyn std::io::Write + Send>>,
-    ) -> Result<Box<dyn Process>, OutputStyleValidationError> {
-        let processor: Box<dyn Process> = match self.output_style {
+    #[cfg(test)]
+    pub fn with_style(output_style: OutputStyle) -> Self {
+        OutputOptions {
+            output_style,
+            row_separator: "\n".to_string(),
+            template: None,
+            json_options: None,
+            text_options: None,
+        }
+    }
+
+    /// The same options with another output style. The template and the options that do not apply to the new
+    /// style are dropped.
+    pub fn restyle(&self, output_style: OutputStyle) -> Self {
+        let json_options = match output_style {
+            OutputStyle::Json | OutputStyle::JsonSeq => self.json_options.clone(),
+            _ => None,
+        };
+        let text_options = match output_style {
+            OutputStyle::Text => self.text_options.clone(),
+            _ => None,
+        };
+        OutputOptions {
+            output_style,
+            row_separator: self.row_separator.clone(),
+            template: None,
+            json_options,
+            text_options,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), OutputStyleValidationError> {
+        if let Some(template) = &self.template {
+            if self.json_options.is_some() {
+                return Err(OutputStyleValidationError::JsonOptionsShouldNotBeHere);
+            }
+            if self.text_options.is_some() {
+                return Err(OutputStyleValidationError::TextOptionsShouldNotBeHere);
+            }
+            Template::from_str(template)?;
+            return Ok(());
+        }
+        match self.output_style {
             OutputStyle::Csv => {
                 if self.json_options.is_some() {
                     return Err(OutputStyleValidationError::JsonOptionsShouldNotBeHere);
@@ -169,6 +223,59 @@ impl OutputOptions {
                 if self.text_options.is_some() {
                     return Err(OutputStyleValidationError::TextOptionsShouldNotBeHere);
                 }
+            }
+            OutputStyle::Text => {
+                if self.json_options.is_some() {
+                    return Err(OutputStyleValidationError::JsonOptionsShouldNotBeHere);
+                }
+            }
+            OutputStyle::Json | OutputStyle::JsonSeq => {
+                if self.text_options.is_some() {
+                    return Err(OutputStyleValidationError::TextOptionsShouldNotBeHere);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn explain(&self) -> Explanation {
+        if let Some(template) = &self.template {
+            return Explanation::new(format!("output using the template `{template}`"));
+        }
+        let style = self
+            .output_style
+            .to_possible_value()
+            .map(|value| value.get_name().to_string())
+            .unwrap_or_default();
+        Explanation::new(format!("output as {style}"))
+    }
+
+    pub fn file_extension(&self) -> &'static str {
+        if self.template.is_some() {
+            return "txt";
+        }
+        match self.output_style {
+            OutputStyle::Csv => "csv",
+            OutputStyle::Text => "txt",
+            OutputStyle::Json => "ndjson",
+            OutputStyle::JsonSeq => "json-seq",
+        }
+    }
+
+    pub fn get_processor(
+        &self,
+        writer: Rc<RefCell<dyn std::io::Write + Send>>,
+    ) -> Result<Box<dyn Process>, OutputStyleValidationError> {
+        self.validate()?;
+        if let Some(template) = &self.template {
+            return Ok(Box::new(TemplateProcess {
+                template: Template::from_str(template)?,
+                line_separator: self.row_separator.clone(),
+                writer,
+            }));
+        }
+        let processor: Box<dyn Process> = match self.output_style {
+            OutputStyle::Csv => {
                 let options = TextOutputOptions::csv();
                 Box::new(TextProcess::new(
                     writer,
@@ -177,9 +284,6 @@ impl OutputOptions {
                 ))
             }
             OutputStyle::Text => {
-                if self.json_options.is_some() {
-                    return Err(OutputStyleValidationError::JsonOptionsShouldNotBeHere);
-                }
                 let options = self.text_options.as_ref().cloned().unwrap_or_default();
                 Box::new(TextProcess::new(
                     writer,
@@ -187,12 +291,15 @@ impl OutputOptions {
                     options,
                 ))
             }
-            OutputStyle::Json => {
-                if self.text_options.is_some() {
-                    return Err(OutputStyleValidationError::TextOptionsShouldNotBeHere);
-                }
+            OutputStyle::Json | OutputStyle::JsonSeq => {
                 let options = self.json_options.as_ref().cloned().unwrap_or_default();
+                let record_prefix = if self.output_style == OutputStyle::JsonSeq {
+                    "\u{1e}"
+                } else {
+                    ""
+                };
                 Box::new(JsonProcess {
+                    record_prefix,
                     line_separator: self.row_separator.clone(),
                     printer: options,
                     writer,
@@ -522,6 +629,7 @@ impl Process for TextProcess {
 }
 
 struct JsonProcess {
+    record_prefix: &'static str,
     line_separator: String,
     printer: JsonOutputOptions,
     writer: Rc<RefCell<dyn std::io::Write + Send>>,
@@ -538,7 +646,35 @@ impl Process for JsonProcess {
         let value = context.build();
         let mut str = String::new();
         self.printer.print_something(&mut str, &value)?;
-        write!(self.writer.borrow_mut(), "{}{}", str, self.line_separator)?;
+        write!(
+            self.writer.borrow_mut(),
+            "{}{}{}",
+            self.record_prefix,
+            str,
+            self.line_separator
+        )?;
+        Ok(ProcessDecision::Continue)
+    }
+}
+
+struct TemplateProcess {
+    template: Template,
+    line_separator: String,
+    writer: Rc<RefCell<dyn std::io::Write + Send>>,
+}
+
+impl Process for TemplateProcess {
+    fn start(&mut self, _: Titles) -> ProcessResult<()> {
+        Ok(())
+    }
+    fn complete(&mut self) -> ProcessResult<()> {
+        Ok(())
+    }
+    fn process(&mut self, context: Context) -> ProcessResult<ProcessDecision> {
+        let value = context.build();
+        if let Some(row) = self.template.render(Some(&value), &context.to_list()) {
+            write!(self.writer.borrow_mut(), "{}{}", row, self.line_separator)?;
+        }
         Ok(ProcessDecision::Continue)
     }
 }
@@ -554,6 +690,7 @@ mod tests {
         let options = OutputOptions {
             output_style: OutputStyle::Csv,
             row_separator: String::new(),
+            template: None,
             json_options: Some(JsonOutputOptions::default()),
             text_options: None,
         };
@@ -570,6 +707,7 @@ mod tests {
         let options = OutputOptions {
             output_style: OutputStyle::Csv,
             row_separator: String::new(),
+            template: None,
             json_options: None,
             text_options: Some(TextOutputOptions::default()),
         };
@@ -586,6 +724,7 @@ mod tests {
         let options = OutputOptions {
             output_style: OutputStyle::Csv,
             row_separator: String::new(),
+            template: None,
             json_options: None,
             text_options: None,
         };
@@ -602,6 +741,7 @@ mod tests {
         let options = OutputOptions {
             output_style: OutputStyle::Text,
             row_separator: String::new(),
+            template: None,
             json_options: Some(JsonOutputOptions::default()),
             text_options: None,
         };
@@ -618,6 +758,7 @@ mod tests {
         let options = OutputOptions {
             output_style: OutputStyle::Text,
             row_separator: String::new(),
+            template: None,
             json_options: None,
             text_options: None,
         };
@@ -634,6 +775,7 @@ mod tests {
         let options = OutputOptions {
             output_style: OutputStyle::Text,
             row_separator: String::new(),
+            template: None,
             json_options: None,
             text_options: Some(TextOutputOptions::default()),
         };
@@ -650,6 +792,7 @@ mod tests {
         let options = OutputOptions {
             output_style: OutputStyle::Json,
             row_separator: String::new(),
+            template: None,
             json_options: None,
             text_options: Some(TextOutputOptions::csv()),
         };
@@ -666,6 +809,7 @@ mod tests {
         let options = OutputOptions {
             output_style: OutputStyle::Json,
             row_separator: String::new(),
+            template: None,
             json_options: None,
             text_options: None,
         };
@@ -682,6 +826,7 @@ mod tests {
         let options = OutputOptions {
             output_style: OutputStyle::Json,
             row_separator: String::new(),
+            template: None,
             json_options: Some(JsonOutputOptions::default()),
             text_options: None,
         };
@@ -693,6 +838,88 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn json_seq_will_start_each_record_with_a_record_separator() -> ProcessResult<()> {
+        let options = OutputOptions::with_style(OutputStyle::JsonSeq);
+        let text = Rc::new(RefCell::new(Vec::new()));
+        let mut process = options.get_processor(text.clone()).unwrap();
+
+        process.process(Context::new_with_no_context(JsonValue::Null))?;
+        process.process(Context::new_with_no_context(JsonValue::from(vec![
+            1.into(),
+        ])))?;
+
+        assert_eq!(text.borrow().as_slice(), b"\x1enull\n\x1e[1]\n");
+        Ok(())
+    }
+
+    #[test]
+    fn restyle_keep_the_options_that_apply_to_the_new_style() -> ProcessResult<()> {
+        let options = OutputOptions {
+            output_style: OutputStyle::Text,
+            row_separator: "---\n".to_string(),
+            template: Some("{name}".to_string()),
+            json_options: None,
+            text_options: Some(TextOutputOptions {
+                null_keyword: "-".to_string(),
+                ..TextOutputOptions::default()
+            }),
+        };
+
+        for (style, expected) in [
+            (OutputStyle::Text, "----\n"),
+            (OutputStyle::Json, "null---\n"),
+        ] {
+            let text = Rc::new(RefCell::new(Vec::new()));
+            let mut process = options.restyle(style).get_processor(text.clone()).unwrap();
+            process.process(Context::new_with_no_context(JsonValue::Null))?;
+
+            assert_eq!(String::from_utf8(text.borrow().clone()).unwrap(), expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn get_processor_will_fail_for_invalid_template() {
+        let options = OutputOptions {
+            template: Some("{name".to_string()),
+            ..OutputOptions::with_style(OutputStyle::Json)
+        };
+        let writer = Rc::new(RefCell::new(Vec::new()));
+
+        let error = options.get_processor(writer);
+
+        assert!(matches!(
+            error,
+            Err(OutputStyleValidationError::InvalidTemplate(_))
+        ));
+    }
+
+    #[test]
+    fn template_will_render_each_row() -> ProcessResult<()> {
+        let options = OutputOptions {
+            template: Some("{name:<4}|{age:>3}".to_string()),
+            ..OutputOptions::with_style(OutputStyle::Json)
+        };
+        let text = Rc::new(RefCell::new(Vec::new()));
+        let mut process = options.get_processor(text.clone()).unwrap();
+
+        for input in [
+            r#"{"name": "Jo", "age": 7}"#,
+            r#"{"name": "Max"}"#,
+            r#"{"name": "Ann", "age": 40}"#,
+        ] {
+            let input = JsonValue::from_str(input).unwrap();
+            process.process(Context::new_with_no_context(input))?;
+        }
+
+        assert_eq!(
+            String::from_utf8(text.borrow().clone()).unwrap(),
+            "Jo  |  7\nAnn | 40\n"
+        );
+        Ok(())
+    }
+
     #[test]
     fn text_printer_will_not_print_missing_values_by_default() {
         let options = TextOutputOptions {
@@ -0,0 +1,444 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    fs::{File, OpenOptions, create_dir_all},
+    io::{BufWriter, Result as IoResult, Write},
+    path::{Path, PathBuf},
+    rc::Rc,
+    str::FromStr,
+};
+
+use crate::{
+    explain::Explanation,
+    json_value::JsonValue,
+    output_style::{OutputOptions, OutputStyleValidationError},
+    processor::{Context, Process, ProcessDecision, Result as ProcessResult, Titles},
+    reader::{Location, from_string},
+    selection::{Get, SelectionParseError, read_getter},
+};
+
+/// An output that was not written, as its partition key can not be used as a file name.
+#[derive(Debug)]
+pub struct InvalidPartition {
+    location: Option<Location>,
+    key: Option<JsonValue>,
+}
+
+impl InvalidPartition {
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+}
+
+impl Display for InvalidPartition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(location) = &self.location {
+            write!(f, "{location}: ")?;
+        }
+        match &self.key {
+            Some(key) => write!(
+                f,
+                "Can not partition the output, `{key}` can not be used as a file name"
+            ),
+            None => write!(
+                f,
+                "Can not partition the output, the partition key is missing"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InvalidPartition {}
+
+#[derive(Clone)]
+pub struct Partitioner {
+    partition_by: Rc<dyn Get>,
+    invalid: Rc<RefCell<Vec<InvalidPartition>>>,
+}
+
+impl FromStr for Partitioner {
+    type Err = SelectionParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let source = s.to_string();
+        let mut reader = from_string(&source);
+        reader.eat_whitespace()?;
+        let partition_by = read_getter(&mut reader)?;
+        reader.eat_whitespace()?;
+        if let Some(ch) = reader.peek()? {
+            return Err(SelectionParseError::ExpectingEof(
+                reader.where_am_i(),
+                ch as char,
+            ));
+        }
+        Ok(Partitioner {
+            partition_by,
+            invalid: Rc::default(),
+        })
+    }
+}
+
+impl Partitioner {
+    pub fn explain(&self, output_dir: &Path) -> Explanation {
+        Explanation::new(format!("partition by into `{}`", output_dir.display()))
+            .with_child(self.partition_by.explain())
+    }
+
+    pub fn uses_raw_input(&self) -> bool {
+        self.partition_by.uses_raw_input()
+    }
+
+    pub fn create_process(
+        &self,
+        output_dir: &Path,
+        output_options: &OutputOptions,
+        max_open_files: usize,
+        flush_each_record: bool,
+    ) -> Result<Box<dyn Process>, OutputStyleValidationError> {
+        output_options.validate()?;
+        Ok(Box::new(PartitionProcess {
+            partition_by: self.partition_by.clone(),
+            invalid: self.invalid.clone(),
+            output_dir: output_dir.to_path_buf(),
+            output_options: output_options.clone(),
+            max_open_files: max_open_files.max(1),
+            flush_each_record,
+            titles: Titles::default(),
+            partitions: HashMap::new(),
+            open_files: 0,
+            clock: 0,
+        }))
+    }
+
+    /// The outputs that were not written since the last call.
+    pub fn take(&self) -> Vec<InvalidPartition> {
+        self.invalid.take()
+    }
+}
+
+struct PartitionFile {
+    path: PathBuf,
+    file: Option<BufWriter<File>>,
+}
+
+impl PartitionFile {
+    fn create(path: PathBuf) -> IoResult<Self> {
+        let file = File::create(&path)?;
+        Ok(PartitionFile {
+            path,
+            file: Some(BufWriter::new(file)),
+        })
+    }
+
+    fn is_open(&self) -> bool {
+        self.file.is_some()
+    }
+
+    fn reopen(&mut self) -> IoResult<()> {
+        if self.file.is_none() {
+            let file = OpenOptions::new().append(true).open(&self.path)?;
+            self.file = Some(BufWriter::new(file));
+        }
+        Ok(())
+    }
+
+    fn close(&mut self) -> IoResult<()> {
+        if let Some(mut file) = self.file.take() {
+            file.flush()?;
+        }
+        Ok(())
+    }
+}
+
+impl Write for PartitionFile {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        self.reopen()?;
+        match &mut self.file {
+            Some(file) => file.write(buf),
+            None => Ok(0),
+        }
+    }
+    fn flush(&mut self) -> IoResult<()> {
+        match &mut self.file {
+            Some(file) => file.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+struct Partition {
+    file: Rc<RefCell<PartitionFile>>,
+    process: Box<dyn Process>,
+    last_used: u64,
+}
+
+struct PartitionProcess {
+    partition_by: Rc<dyn Get>,
+    invalid: Rc<RefCell<Vec<InvalidPartition>>>,
+    output_dir: PathBuf,
+    output_options: OutputOptions,
+    max_open_files: usize,
+    flush_each_record: bool,
+    titles: Titles,
+    partitions: HashMap<String, Partition>,
+    open_files: usize,
+    clock: u64,
+}
+
+impl Process for PartitionProcess {
+    fn start(&mut self, titles_so_far: Titles) -> ProcessResult<()> {
+        create_dir_all(&self.output_dir)?;
+        self.titles = titles_so_far;
+        Ok(())
+    }
+    fn complete(&mut self) -> ProcessResult<()> {
+        for partition in self.partitions.values_mut() {
+            partition.process.complete()?;
+            partition.file.borrow_mut().close()?;
+        }
+        self.partitions.clear();
+        self.open_files = 0;
+        Ok(())
+    }
+    fn process(&mut self, context: Context) -> ProcessResult<ProcessDecision> {
+        let name = match self.name(&context) {
+            Ok(name) => name,
+            Err(key) => {
+                let location = context
+                    .input_context()
+                    .map(|input_context| input_context.start_location.clone());
+                self.invalid
+                    .borrow_mut()
+                    .push(InvalidPartition { location, key });
+                return Ok(ProcessDecision::Continue);
+            }
+        };
+        self.clock += 1;
+        if let Some(file) = self.partitions.get(&name).map(|p| p.file.clone()) {
+            if !file.borrow().is_open() {
+                self.make_room()?;
+                file.borrow_mut().reopen()?;
+                self.open_files += 1;
+            }
+        } else {
+            self.make_room()?;
+            let path =
+                self.output_dir
+                    .join(format!("{}.{}", name, self.output_options.file_extension()));
+            let file = Rc::new(RefCell::new(PartitionFile::create(path)?));
+            self.open_files += 1;
+            let mut process = self.output_options.get_processor(file.clone())?;
+            process.start(self.titles.clone())?;
+            self.partitions.insert(
+                name.clone(),
+                Partition {
+                    file,
+                    process,
+                    last_used: self.clock,
+                },
+            );
+        }
+        if let Some(partition) = self.partitions.get_mut(&name) {
+            partition.last_used = self.clock;
+            partition.process.process(context)?;
+            if self.flush_each_record {
+                partition.file.borrow_mut().flush()?;
+            }
+        }
+        Ok(ProcessDecision::Continue)
+    }
+}
+
+impl PartitionProcess {
+    /// The file name of the partition, or the key if it can not be used as a file name.
+    fn name(&self, context: &Context) -> Result<String, Option<JsonValue>> {
+        let key = self.partition_by.get(context);
+        let name = match &key {
+            Some(JsonValue::String(str)) => str.clone(),
+            Some(key @ (JsonValue::Number(_) | JsonValue::Boolean(_))) => key.to_string(),
+            _ => return Err(key),
+        };
+        if name.is_empty() || name == "." || name == ".." || name.contains(['/', '\\', '\0']) {
+            Err(key)
+        } else {
+            Ok(name)
+        }
+    }
+
+    fn make_room(&mut self) -> IoResult<()> {
+        if self.open_files < self.max_open_files {
+            return Ok(());
+        }
+        let least_recently_used = self
+            .partitions
+            .values()
+            .filter(|p| p.file.borrow().is_open())
+            .min_by_key(|p| p.last_used);
+        if let Some(partition) = least_recently_used {
+            partition.file.borrow_mut().close()?;
+            self.open_files -= 1;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::{read_to_string, remove_dir_all};
+
+    use clap::Parser;
+
+    use super::*;
+    use crate::Cli;
+
+    fn output_options(args: &[&str]) -> OutputOptions {
+        let mut all_args = vec!["jawk"];
+        all_args.extend(args);
+        Cli::parse_from(all_args).output_options
+    }
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = temp_dir().join(format!("jawk-partitioner-{}-{}", name, std::process::id()));
+        remove_dir_all(&dir).ok();
+        dir
+    }
+
+    #[test]
+    fn parse_parse_correctly() {
+        let str = "(.len)";
+        let partitioner = Partitioner::from_str(str).unwrap();
+
+        let input = Context::new_with_no_context("test".into());
+
+        assert_eq!(partitioner.partition_by.get(&input), Some((4).into()));
+    }
+
+    #[test]
+    fn parse_fail_if_too_long() {
+        let str = "(.len)3";
+        let err = Partitioner::from_str(str).err().unwrap();
+
+        assert!(matches!(err, SelectionParseError::ExpectingEof(_, _)));
+    }
+
+    #[test]
+    fn create_process_fail_with_invalid_output_options() {
+        let partitioner = Partitioner::from_str(".").unwrap();
+        let options = output_options(&["--output-style=csv", "--style=pretty"]);
+
+        let result = partitioner.create_process(&test_dir("invalid"), &options, 10, false);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn process_will_write_each_partition_to_its_own_file() -> ProcessResult<()> {
+        let dir = test_dir("json");
+        let partitioner = Partitioner::from_str(".day").unwrap();
+        let options = output_options(&[]);
+        let mut process = partitioner.create_process(&dir, &options, 10, false)?;
+
+        process.start(Titles::default())?;
+        for (day, value) in [("one", 1), ("two", 2), ("one", 3)] {
+            let input =
+                JsonValue::from_str(&format!(r#"{{"day": "{day}", "value": {value}}}"#)).unwrap();
+            process.process(Context::new_with_no_context(input))?;
+        }
+        process.process(Context::new_with_no_context((1).into()))?;
+        process.complete()?;
+
+        assert_eq!(
+            read_to_string(dir.join("one.ndjson"))?,
+            "{\"day\": \"one\", \"value\": 1}\n{\"day\": \"one\", \"value\": 3}\n"
+        );
+        assert_eq!(
+            read_to_string(dir.join("two.ndjson"))?,
+            "{\"day\": \"two\", \"value\": 2}\n"
+        );
+        remove_dir_all(&dir)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn process_will_write_headers_once_when_files_are_reopened() -> ProcessResult<()> {
+        let dir = test_dir("csv");
+        let partitioner = Partitioner::from_str("/key/").unwrap();
+        let options = output_options(&["--output-style=csv"]);
+        let mut process = partitioner.create_process(&dir, &options, 1, false)?;
+        let key = Rc::new("key".to_string());
+        let value = Rc::new("value".to_string());
+
+        process.start(Titles::default().with_title(&key).with_title(&value))?;
+        for (k, v) in [("a", 1), ("b", 2), ("a", 3), ("b", 4)] {
+            let context = Context::new_with_no_context(JsonValue::Null)
+                .with_result(&key, Some(k.into()))
+                .with_result(&value, Some(v.into()));
+            process.process(context)?;
+        }
+        process.complete()?;
+
+        assert_eq!(
+            read_to_string(dir.join("a.csv"))?,
+            "\"key\", \"value\"\n\"a\", 1\n\"a\", 3\n"
+        );
+        assert_eq!(
+            read_to_string(dir.join("b.csv"))?,
+            "\"key\", \"value\"\n\"b\", 2\n\"b\", 4\n"
+        );
+        remove_dir_all(&dir)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn process_will_ignore_invalid_file_names() -> ProcessResult<()> {
+        let dir = test_dir("names");
+        let partitioner = Partitioner::from_str(".").unwrap();
+        let options = output_options(&[]);
+        let mut process = partitioner.create_process(&dir, &options, 10, false)?;
+
+        process.start(Titles::default())?;
+        for name in ["", ".", "..", "../up", "a/b"] {
+            process.process(Context::new_with_no_context(name.into()))?;
+        }
+        process.complete()?;
+
+        assert_eq!(std::fs::read_dir(&dir)?.count(), 0);
+        assert_eq!(partitioner.take().len(), 5);
+        remove_dir_all(&dir)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn process_will_name_files_after_numbers_and_booleans() -> ProcessResult<()> {
+        let dir = test_dir("scalars");
+        let partitioner = Partitioner::from_str(".k").unwrap();
+        let options = output_options(&[]);
+        let mut process = partitioner.create_process(&dir, &options, 10, false)?;
+
+        process.start(Titles::default())?;
+        for input in [r#"{"k": 2024}"#, r#"{"k": true}"#, r#"{"k": [1]}"#, "{}"] {
+            let input = JsonValue::from_str(input).unwrap();
+            process.process(Context::new_with_no_context(input))?;
+        }
+        process.complete()?;
+
+        assert_eq!(read_to_string(dir.join("2024.ndjson"))?, "{\"k\": 2024}\n");
+        assert_eq!(read_to_string(dir.join("true.ndjson"))?, "{\"k\": true}\n");
+        let invalid: Vec<_> = partitioner.take().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            invalid,
+            vec![
+                "Can not partition the output, `[1]` can not be used as a file name",
+                "Can not partition the output, the partition key is missing",
+            ]
+        );
+        assert!(partitioner.take().is_empty());
+        remove_dir_all(&dir)?;
+
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@ use std::{collections::HashMap, rc::Rc, str::FromStr};
 use thiserror::Error;
 
 use crate::{
+    explain::Explanation,
     json_value::JsonValue,
     processor::{Context, Process, ProcessDecision, Result as ProcessResult, Titles},
     reader::from_string,
@@ -76,6 +77,8 @@ impl FromStr for PreSet {
 pub trait PreSetCollection {
     fn create_process(&self, next: Box<dyn Process>)
     -> Result<Box<dyn Process>, PreSetParserError>;
+    fn explain(&self) -> Result<Explanation, PreSetParserError>;
+    fn uses_raw_input(&self) -> Result<bool, PreSetParserError>;
 }
 
 struct PreSetProcessor {
@@ -115,6 +118,33 @@ impl PreSetCollection for Vec<String> {
             next,
         }))
     }
+    fn explain(&self) -> Result<Explanation, PreSetParserError> {
+        let mut explanation = Explanation::new("set");
+        for p in self {
+            let p = PreSet::from_str(p)?;
+            let child = match &p.value {
+                Value::Calculated(value) => {
+                    Explanation::new(format!("variable `:{}` = `{value}`", p.key))
+                }
+                Value::Macro(getter) => {
+                    Explanation::new(format!("macro `@{}`", p.key)).with_child(getter.explain())
+                }
+            };
+            explanation = explanation.with_child(child);
+        }
+        Ok(explanation)
+    }
+    fn uses_raw_input(&self) -> Result<bool, PreSetParserError> {
+        for p in self {
+            let p = PreSet::from_str(p)?;
+            if let Value::Macro(getter) = &p.value
+                && getter.uses_raw_input()
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
 }
 
 impl Process for PreSetProcessor {
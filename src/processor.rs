@@ -1,5 +1,6 @@
 use std::collections::HashMap;
-use std::ops::Deref;
+use std::ops::{Deref, Range};
+use std::path::PathBuf;
 use std::prelude::v1::Result as StdResult;
 use std::rc::Rc;
 
@@ -10,9 +11,11 @@ use std::io::Error as IoError;
 use thiserror::Error;
 
 use crate::json_value::JsonValue;
+use crate::output_style::OutputStyleValidationError;
 use crate::reader::Location;
 use crate::regex_cache::{RegexCache, RegexCompile};
 use crate::selection::Get;
+use crate::strict::{EvaluationError, StrictErrors};
 
 use regex::Error as RegexError;
 
@@ -24,9 +27,11 @@ pub enum ProcessError {
     Io(#[from] IoError),
     #[error("{0}")]
     InvalidInputError(&'static str),
+    #[error("{0}")]
+    OutputStyle(#[from] OutputStyleValidationError),
 }
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct Titles {
     titles: Vec<Rc<String>>,
 }
@@ -64,8 +69,26 @@ pub struct InputContext {
     pub end_location: Location,
     pub file_index: u64,
     pub index: u64,
+    pub source: Option<InputSource>,
+    pub file: Option<Rc<InputFile>>,
+}
+
+/// The file from which the input was read.
+pub struct InputFile {
+    pub path: PathBuf,
+    pub size: u64,
+    pub modified: Option<f64>,
+    pub index: u64,
+}
+
+/// The original bytes of the input (if they were kept) and their offsets within the input file.
+#[derive(Clone)]
+pub struct InputSource {
+    pub raw: Option<Rc<[u8]>>,
+    pub byte_offsets: Range<u64>,
 }
 
+#[derive(Clone)]
 pub struct Context {
     input: Rc<JsonValue>,
     results: Vec<(Rc<String>, Option<JsonValue>)>,
@@ -74,6 +97,7 @@ pub struct Context {
     definitions: Rc<HashMap<String, Rc<dyn Get>>>,
     input_context: Option<Rc<InputContext>>,
     regex_cache: RegexCache,
+    strict_errors: Option<StrictErrors>,
 }
 
 impl Context {
@@ -86,6 +110,7 @@ impl Context {
             definitions: Rc::new(HashMap::new()),
             input_context: None,
             regex_cache: RegexCache::new(0),
+            strict_errors: None,
         }
     }
     pub fn new_with_no_context(input: JsonValue) -> Self {
@@ -97,6 +122,7 @@ impl Context {
             definitions: Rc::new(HashMap::new()),
             input_context: None,
             regex_cache: RegexCache::new(0),
+            strict_errors: None,
         }
     }
     pub fn new_with_input(
@@ -112,6 +138,8 @@ impl Context {
             end_location,
             file_index,
             index,
+            source: None,
+            file: None,
         };
         Context {
             input: Rc::new(input),
@@ -121,6 +149,7 @@ impl Context {
             definitions: Rc::new(HashMap::new()),
             input_context: Some(Rc::new(input_context)),
             regex_cache: regex_cache.clone(),
+            strict_errors: None,
         }
     }
     pub fn with_inupt(&self, value: JsonValue) -> Self {
@@ -138,6 +167,7 @@ impl Context {
             definitions: self.definitions.clone(),
             input_context: self.input_context.clone(),
             regex_cache: self.regex_cache.clone(),
+            strict_errors: self.strict_errors.clone(),
         }
     }
     pub fn with_result(&self, title: &Rc<String>, result: Option<JsonValue>) -> Self {
@@ -151,6 +181,7 @@ impl Context {
             definitions: self.definitions.clone(),
             input_context: self.input_context.clone(),
             regex_cache: self.regex_cache.clone(),
+            strict_errors: self.strict_errors.clone(),
         }
     }
     pub fn with_variable(&self, name: String, value: JsonValue) -> Self {
@@ -167,6 +198,7 @@ impl Context {
             definitions: self.definitions.clone(),
             input_context: self.input_context.clone(),
             regex_cache: self.regex_cache.clone(),
+            strict_errors: self.strict_errors.clone(),
         }
     }
     pub fn with_variables(&self, variables: &Rc<HashMap<String, JsonValue>>) -> Self {
@@ -178,6 +210,7 @@ impl Context {
             definitions: self.definitions.clone(),
             input_context: self.input_context.clone(),
             regex_cache: self.regex_cache.clone(),
+            strict_errors: self.strict_errors.clone(),
         }
     }
     pub fn with_definition(&self, name: String, definition: &Rc<dyn Get>) -> Self {
@@ -194,6 +227,7 @@ impl Context {
             definitions: Rc::new(definitions),
             input_context: self.input_context.clone(),
             regex_cache: self.regex_cache.clone(),
+            strict_errors: self.strict_errors.clone(),
         }
     }
     pub fn with_definitions(&self, definitions: &Rc<HashMap<String, Rc<dyn Get>>>) -> Self {
@@ -205,6 +239,7 @@ impl Context {
             definitions: definitions.clone(),
             input_context: self.input_context.clone(),
             regex_cache: self.regex_cache.clone(),
+            strict_errors: self.strict_errors.clone(),
         }
     }
     pub fn build(&self) -> JsonValue {
@@ -232,6 +267,10 @@ impl Context {
         self.definitions.get(name)
     }
 
+    pub fn has_selection(&self, name: &String) -> bool {
+        self.results.iter().any(|(title, _)| &**title == name)
+    }
+
     pub fn get_selected(&self, name: &String) -> Option<JsonValue> {
         for (title, result) in &self.results {
             if &**title == name {
@@ -266,11 +305,52 @@ impl Context {
     pub fn input_context(&self) -> Option<Rc<InputContext>> {
         self.input_context.clone()
     }
+
+    pub fn with_source(mut self, source: InputSource) -> Self {
+        if let Some(input_context) = &mut self.input_context {
+            Rc::make_mut(input_context).source = Some(source);
+        }
+        self
+    }
+
+    pub fn with_input_file(mut self, file: &Rc<InputFile>) -> Self {
+        if let Some(input_context) = &mut self.input_context {
+            Rc::make_mut(input_context).file = Some(file.clone());
+        }
+        self
+    }
+
+    pub fn with_strict_errors(mut self, strict_errors: &StrictErrors) -> Self {
+        self.strict_errors = Some(strict_errors.clone());
+        self
+    }
+
+    pub fn is_strict(&self) -> bool {
+        self.strict_errors.is_some()
+    }
+
+    pub fn strict_errors(&self) -> Option<&StrictErrors> {
+        self.strict_errors.as_ref()
+    }
+
+    pub fn report(&self, error: impl FnOnce() -> EvaluationError) {
+        if let Some(strict_errors) = &self.strict_errors {
+            let location = self
+                .input_context
+                .as_ref()
+                .map(|context| context.start_location.clone());
+            strict_errors.report(location, error());
+        }
+    }
 }
 
 impl RegexCompile for Context {
     fn compile_regex(&self, regex: &str) -> Rc<StdResult<Regex, RegexError>> {
-        self.regex_cache.compile_regex(regex)
+        let compiled = self.regex_cache.compile_regex(regex);
+        if let Err(e) = compiled.as_ref() {
+            self.report(|| EvaluationError::InvalidRegex(regex.to_string(), e.to_string()));
+        }
+        compiled
     }
 }
 
@@ -2,9 +2,14 @@ use std::{
     fmt::Display,
     fs::File,
     io::{BufReader, Bytes, Read, Result},
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
+use crate::follow::FollowedFile;
+
+/// The ASCII record separator, that starts each value of a JSON text sequence (RFC 7464).
+pub const RECORD_SEPARATOR: u8 = 0x1e;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Location {
     pub input: Option<String>,
@@ -17,6 +22,9 @@ pub struct Reader<R: Read> {
     current_byte: Option<u8>,
     location: Location,
     eof: bool,
+    capture: Option<Vec<u8>>,
+    stop_at_new_line: bool,
+    bytes_read: u64,
 }
 
 pub fn from_file(file_name: &PathBuf) -> Result<Reader<File>> {
@@ -27,6 +35,14 @@ pub fn from_file(file_name: &PathBuf) -> Result<Reader<File>> {
     ))
 }
 
+pub fn from_followed_file(file_name: &Path) -> Result<Reader<FollowedFile>> {
+    let file = FollowedFile::open(file_name)?;
+    Ok(Reader::new(
+        file,
+        file_name.to_str().map(ToString::to_string),
+    ))
+}
+
 pub fn from_std_in<R: Read>(stdin: R) -> Reader<R> {
     Reader::new(stdin, None)
 }
@@ -50,6 +66,9 @@ impl<R: Read> Reader<R> {
             current_byte: Option::None,
             location,
             eof: false,
+            capture: None,
+            stop_at_new_line: false,
+            bytes_read: 0,
         }
     }
 
@@ -66,6 +85,7 @@ impl<R: Read> Reader<R> {
             }
             Some(ch) => {
                 let ch = ch?;
+                self.bytes_read += 1;
                 if ch == b'\n' {
                     self.location.line_number += 1;
                     self.location.char_number = 1;
@@ -73,6 +93,12 @@ impl<R: Read> Reader<R> {
                     self.location.char_number += 1;
                 }
                 self.current_byte = Some(ch);
+                if let Some(capture) = &mut self.capture {
+                    capture.push(ch);
+                }
+                if ch == b'\n' && self.stop_at_new_line {
+                    return Ok(None);
+                }
                 Ok(self.current_byte)
             }
         }
@@ -81,6 +107,7 @@ impl<R: Read> Reader<R> {
     #[inline]
     pub fn peek(&mut self) -> Result<Option<u8>> {
         match self.current_byte {
+            Some(b'\n') if self.stop_at_new_line => Ok(None),
             Some(ch) => Ok(Some(ch)),
             None => self.next(),
         }
@@ -100,6 +127,17 @@ impl<R: Read> Reader<R> {
         }
     }
 
+    /// Skip the white spaces and record separators between two top level values.
+    pub fn eat_record_separators(&mut self) -> Result<()> {
+        loop {
+            self.eat_whitespace()?;
+            if self.peek()? != Some(RECORD_SEPARATOR) {
+                return Ok(());
+            }
+            self.next()?;
+        }
+    }
+
     #[inline]
     pub fn read_digits(&mut self, digits: &mut Vec<u8>) -> Result<()> {
         loop {
@@ -116,9 +154,43 @@ impl<R: Read> Reader<R> {
         }
     }
 
+    /// When set, a new line will look like the end of the input (until the flag is cleared).
+    pub fn stop_at_new_line(&mut self, stop: bool) {
+        self.stop_at_new_line = stop;
+    }
+
+    /// The offset in bytes of the current byte (or of the end of the input).
+    pub fn byte_offset(&self) -> u64 {
+        if self.current_byte.is_some() {
+            self.bytes_read - 1
+        } else {
+            self.bytes_read
+        }
+    }
+
     pub fn where_am_i(&self) -> Location {
         self.location.clone()
     }
+
+    /// Start to keep the bytes that are read, including the one that was peeked and not consumed yet.
+    pub fn start_capture(&mut self) {
+        let mut capture = Vec::new();
+        capture.extend(self.current_byte);
+        self.capture = Some(capture);
+    }
+
+    /// Return the bytes that were consumed since the last `start_capture`, and start a new capture (nothing is
+    /// returned if the capture was never started).
+    pub fn take_capture(&mut self) -> Vec<u8> {
+        let Some(mut capture) = self.capture.take() else {
+            return Vec::new();
+        };
+        if self.current_byte.is_some() {
+            capture.pop();
+        }
+        self.start_capture();
+        capture
+    }
 }
 
 impl Display for Location {
@@ -217,6 +289,55 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_capture_return_the_consumed_bytes() -> Result<()> {
+        let str = "ab cd".to_string();
+        let mut reader = from_string(&str);
+        reader.next()?;
+        reader.start_capture();
+        reader.next()?;
+        reader.next()?;
+
+        assert_eq!(reader.take_capture(), b"ab");
+        reader.next()?;
+        reader.next()?;
+        assert_eq!(reader.take_capture(), b" c");
+        reader.next()?;
+        reader.next()?;
+        assert_eq!(reader.take_capture(), b"d");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_capture_is_empty_if_not_started() -> Result<()> {
+        let str = "ab".to_string();
+        let mut reader = from_string(&str);
+        reader.next()?;
+        reader.next()?;
+
+        assert_eq!(reader.take_capture(), b"");
+        reader.next()?;
+        assert_eq!(reader.take_capture(), b"");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_byte_offset_is_the_offset_of_the_current_byte() -> Result<()> {
+        let str = "ab".to_string();
+        let mut reader = from_string(&str);
+        assert_eq!(reader.byte_offset(), 0);
+        reader.peek()?;
+        assert_eq!(reader.byte_offset(), 0);
+        reader.next()?;
+        assert_eq!(reader.byte_offset(), 1);
+        reader.next()?;
+        assert_eq!(reader.byte_offset(), 2);
+
+        Ok(())
+    }
+
     #[test]
     fn test_location_is_correct() -> Result<()> {
         let str = "a\nb\ncde".to_string();
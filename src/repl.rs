@@ -0,0 +1,474 @@
+use std::{
+    cell::RefCell,
+    collections::BTreeSet,
+    env,
+    io::{Error as IoError, IsTerminal, Write, stdin},
+    path::PathBuf,
+    rc::Rc,
+    str::FromStr,
+};
+
+use rustyline::{
+    Behavior, Config, Editor, Helper, completion::Completer, error::ReadlineError,
+    highlight::Highlighter, hint::Hinter, history::DefaultHistory, validate::Validator,
+};
+use thiserror::Error;
+
+use crate::{
+    filter::Filter,
+    functions_definitions::all_function_names,
+    input_context_extractor::input_context_names,
+    json_value::JsonValue,
+    output_style::{OutputOptions, OutputStyleValidationError},
+    pre_sets::{PreSetCollection, PreSetParserError},
+    processor::{Context, Process, ProcessDecision, ProcessError, Result as ProcessResult, Titles},
+    selection::{Selection, SelectionParseError},
+    selection_diagnostic::SelectionDiagnostic,
+};
+
+const HELP: &str = r"Type a selection to see its value for each of the sample inputs.
+Commands:
+  \filter <selection>   Only use the samples that match the filter.
+  \filter               Remove the filter.
+  \set <key>=<value>    Predefine a variable (or a macro, if the key starts with `@`).
+  \unset <key>          Remove a predefined variable or macro.
+  \show                 Show the number of samples, the filter and the predefined values.
+  \reset                Remove the filter and all the predefined values.
+  \help                 Show this help.
+  \quit                 Exit.
+Use the TAB key to complete functions names, input context names and the keys of the samples.";
+
+#[derive(Debug, Error)]
+pub enum ReplError {
+    #[error("{0}")]
+    Readline(#[from] ReadlineError),
+    #[error("{0}")]
+    Io(#[from] IoError),
+    #[error("{0}")]
+    SelectionParse(#[from] SelectionParseError),
+    #[error("{0}")]
+    SelectionDiagnostic(#[from] SelectionDiagnostic),
+    #[error("{0}")]
+    PreSet(#[from] PreSetParserError),
+    #[error("{0}")]
+    Processor(#[from] ProcessError),
+    #[error("{0}")]
+    OutputStyle(#[from] OutputStyleValidationError),
+    #[error("Unknown command `{0}`, use `\\help` to see the available commands")]
+    UnknownCommand(String),
+}
+
+type Result<T> = std::result::Result<T, ReplError>;
+
+#[derive(Debug, PartialEq)]
+enum ReplAction {
+    Continue,
+    Quit,
+}
+
+pub struct SampleCollector {
+    samples: Rc<RefCell<Vec<Context>>>,
+}
+
+impl SampleCollector {
+    pub fn create_process(samples: &Rc<RefCell<Vec<Context>>>) -> Box<dyn Process> {
+        Box::new(SampleCollector {
+            samples: samples.clone(),
+        })
+    }
+}
+
+impl Process for SampleCollector {
+    fn start(&mut self, _: Titles) -> ProcessResult<()> {
+        Ok(())
+    }
+    fn process(&mut self, context: Context) -> ProcessResult<ProcessDecision> {
+        self.samples.borrow_mut().push(context);
+        Ok(ProcessDecision::Continue)
+    }
+    fn complete(&mut self) -> ProcessResult<()> {
+        Ok(())
+    }
+}
+
+pub struct Repl {
+    samples: Vec<Context>,
+    output_options: OutputOptions,
+    sets: Vec<String>,
+    filter: Option<String>,
+    stdout: Rc<RefCell<dyn Write + Send>>,
+    stderr: Rc<RefCell<dyn Write + Send>>,
+}
+
+impl Repl {
+    pub fn new(
+        samples: Vec<Context>,
+        output_options: OutputOptions,
+        sets: Vec<String>,
+        filter: Option<String>,
+        stdout: Rc<RefCell<dyn Write + Send>>,
+        stderr: Rc<RefCell<dyn Write + Send>>,
+    ) -> Self {
+        Repl {
+            samples,
+            output_options,
+            sets,
+            filter,
+            stdout,
+            stderr,
+        }
+    }
+
+    pub fn run(&mut self) -> Result<()> {
+        let helper = ReplHelper::new(&self.samples);
+        // When the samples are piped into the standard input, the commands are read from the terminal.
+        let behavior = if stdin().is_terminal() {
+            Behavior::Stdio
+        } else {
+            Behavior::PreferTerm
+        };
+        let config = Config::builder().behavior(behavior).build();
+        let mut editor: Editor<ReplHelper, DefaultHistory> = Editor::with_config(config)?;
+        editor.set_helper(Some(helper));
+        let history = history_file();
+        if let Some(history) = &history {
+            editor.load_history(history).ok();
+        }
+        writeln!(
+            self.stdout.borrow_mut(),
+            "Loaded {} samples. Type `\\help` for help.",
+            self.samples.len()
+        )?;
+        loop {
+            let line = match editor.readline("jawk> ") {
+                Ok(line) => line,
+                Err(ReadlineError::Interrupted) => continue,
+                Err(ReadlineError::Eof) => break,
+                Err(e) => return Err(e.into()),
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            editor.add_history_entry(line.as_str())?;
+            match self.execute(&line) {
+                Ok(ReplAction::Quit) => break,
+                Ok(ReplAction::Continue) => {}
+                Err(e) => writeln!(self.stderr.borrow_mut(), "{e}")?,
+            }
+            self.stdout.borrow_mut().flush()?;
+        }
+        if let Some(history) = &history {
+            editor.save_history(history).ok();
+        }
+        Ok(())
+    }
+
+    fn execute(&mut self, line: &str) -> Result<ReplAction> {
+        let line = line.trim();
+        let Some(command) = line.strip_prefix('\\') else {
+            self.evaluate(line)?;
+            return Ok(ReplAction::Continue);
+        };
+        let (command, argument) = match command.find(char::is_whitespace) {
+            Some(index) => (&command[..index], command[index..].trim()),
+            None => (command, ""),
+        };
+        match command {
+            "q" | "quit" | "exit" => return Ok(ReplAction::Quit),
+            "help" | "h" | "?" => writeln!(self.stdout.borrow_mut(), "{HELP}")?,
+            "filter" => {
+                if argument.is_empty() {
+                    self.filter = None;
+                } else {
+                    Filter::from_str(argument).map_err(|e| e.with_source(argument))?;
+                    self.filter = Some(argument.to_string());
+                }
+            }
+            "set" => {
+                let mut sets = self.sets.clone();
+                sets.push(argument.to_string());
+                sets.create_process(Box::new(SampleCollector {
+                    samples: Rc::new(RefCell::new(vec![])),
+                }))?;
+                self.sets = sets;
+            }
+            "unset" => {
+                self.sets.retain(|set| match set.find('=') {
+                    Some(index) => set[..index].trim() != argument,
+                    None => true,
+                });
+            }
+            "reset" => {
+                self.sets.clear();
+                self.filter = None;
+            }
+            "show" => {
+                let mut stdout = self.stdout.borrow_mut();
+                writeln!(stdout, "Samples: {}", self.samples.len())?;
+                if let Some(filter) = &self.filter {
+                    writeln!(stdout, "Filter: {filter}")?;
+                }
+                for set in &self.sets {
+                    writeln!(stdout, "Set: {set}")?;
+                }
+            }
+            _ => return Err(ReplError::UnknownCommand(command.to_string())),
+        }
+        Ok(ReplAction::Continue)
+    }
+
+    fn evaluate(&self, selection: &str) -> Result<()> {
+        let mut process = self.output_options.get_processor(self.stdout.clone())?;
+        let selection = Selection::from_str(selection).map_err(|e| e.with_source(selection))?;
+        process = selection.create_process(process);
+        if let Some(filter) = &self.filter {
+            let filter = Filter::from_str(filter).map_err(|e| e.with_source(filter))?;
+            process = filter.create_process(process);
+        }
+        process = self.sets.create_process(process)?;
+        process.start(Titles::default())?;
+        for sample in &self.samples {
+            let decision = process.process(sample.clone())?;
+            if let Some(strict_errors) = sample.strict_errors() {
+                for e in strict_errors.take() {
+                    writeln!(self.stderr.borrow_mut(), "{e}")?;
+                }
+            }
+            if decision == ProcessDecision::Break {
+                break;
+            }
+        }
+        process.complete()?;
+        Ok(())
+    }
+}
+
+fn history_file() -> Option<PathBuf> {
+    env::var_os("HOME").map(|home| PathBuf::from(home).join(".jawk_history"))
+}
+
+struct ReplHelper {
+    functions: Vec<String>,
+    input_context: Vec<String>,
+    keys: Vec<String>,
+}
+
+impl ReplHelper {
+    fn new(samples: &[Context]) -> Self {
+        let mut functions: Vec<String> = all_function_names().map(ToString::to_string).collect();
+        functions.sort();
+        let input_context = input_context_names().map(ToString::to_string).collect();
+        let mut keys = BTreeSet::new();
+        for sample in samples {
+            collect_keys(sample.input(), &mut keys);
+        }
+        ReplHelper {
+            functions,
+            input_context,
+            keys: keys.into_iter().collect(),
+        }
+    }
+
+    fn candidates(&self, before: &str, word: &str) -> Vec<String> {
+        let mut candidates = Vec::new();
+        if let Some(context) = word.strip_prefix('&') {
+            for name in &self.input_context {
+                if name.starts_with(context) {
+                    candidates.push(format!("&{name}"));
+                }
+            }
+        } else if let Some(index) = word.rfind('.') {
+            let (prefix, key) = (&word[..=index], &word[index + 1..]);
+            for name in &self.keys {
+                if name.starts_with(key) {
+                    candidates.push(format!("{prefix}{name}"));
+                }
+            }
+            if before.ends_with('(') && index == 0 {
+                for name in &self.functions {
+                    if name.starts_with(key) {
+                        candidates.push(format!(".{name}"));
+                    }
+                }
+            }
+        } else if before.ends_with('(') {
+            for name in &self.functions {
+                if name.starts_with(word) {
+                    candidates.push(name.clone());
+                }
+            }
+        }
+        candidates
+    }
+}
+
+fn collect_keys(value: &JsonValue, keys: &mut BTreeSet<String>) {
+    match value {
+        JsonValue::Object(map) => {
+            for (key, value) in map {
+                keys.insert(key.clone());
+                collect_keys(value, keys);
+            }
+        }
+        JsonValue::Array(list) => {
+            for value in list {
+                collect_keys(value, keys);
+            }
+        }
+        _ => {}
+    }
+}
+
+impl Completer for ReplHelper {
+    type Candidate = String;
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _: &rustyline::Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let line = &line[..pos];
+        let start = line
+            .rfind(|ch: char| ch.is_whitespace() || ch == '(' || ch == ')' || ch == ',')
+            .map_or(0, |index| index + 1);
+        let before = line[..start].trim_end_matches(char::is_whitespace);
+        Ok((start, self.candidates(before, &line[start..])))
+    }
+}
+
+impl Hinter for ReplHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ReplHelper {}
+
+impl Validator for ReplHelper {}
+
+impl Helper for ReplHelper {}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+    use crate::Cli;
+
+    type Buffer = Rc<RefCell<Vec<u8>>>;
+
+    fn create_repl(args: &[&str]) -> (Repl, Buffer, Buffer) {
+        let mut all_args = vec!["jawk"];
+        all_args.extend(args);
+        let cli = Cli::parse_from(all_args);
+        let samples = [
+            r#"{"name": "John", "age": 30, "address": {"city": "London"}}"#,
+            r#"{"name": "Jane", "age": 40}"#,
+        ]
+        .iter()
+        .map(|s| Context::new_with_no_context(JsonValue::from_str(s).unwrap()))
+        .collect();
+        let stdout = Rc::new(RefCell::new(Vec::new()));
+        let stderr = Rc::new(RefCell::new(Vec::new()));
+        let repl = Repl::new(
+            samples,
+            cli.output_options,
+            cli.set,
+            cli.filter,
+            stdout.clone(),
+            stderr.clone(),
+        );
+        (repl, stdout, stderr)
+    }
+
+    fn text(data: &Buffer) -> String {
+        let text = String::from_utf8(data.borrow().clone()).unwrap();
+        data.borrow_mut().clear();
+        text
+    }
+
+    #[test]
+    fn execute_evaluate_selections() -> Result<()> {
+        let (mut repl, stdout, _) = create_repl(&["--output-style=text"]);
+
+        let action = repl.execute("(.len)")?;
+
+        assert_eq!(action, ReplAction::Continue);
+        assert_eq!(text(&stdout), "3\n2\n");
+        Ok(())
+    }
+
+    #[test]
+    fn execute_use_the_filter() -> Result<()> {
+        let (mut repl, stdout, _) = create_repl(&[]);
+
+        repl.execute(r"\filter (> .age 35)")?;
+        repl.execute(".name")?;
+        assert_eq!(text(&stdout), "{\".name\": \"Jane\"}\n");
+
+        repl.execute(r"\filter")?;
+        repl.execute(".name")?;
+        assert_eq!(
+            text(&stdout),
+            "{\".name\": \"John\"}\n{\".name\": \"Jane\"}\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn execute_use_the_sets() -> Result<()> {
+        let (mut repl, stdout, _) = create_repl(&["--output-style=text", "--set=one=1"]);
+
+        repl.execute(r"\set @older=(+ .age :one)")?;
+        repl.execute("@older")?;
+        assert_eq!(text(&stdout), "31\n41\n");
+
+        repl.execute(r"\unset @older")?;
+        repl.execute(r"\show")?;
+        assert_eq!(text(&stdout), "Samples: 2\nSet: one=1\n");
+
+        repl.execute(r"\reset")?;
+        repl.execute(r"\show")?;
+        assert_eq!(text(&stdout), "Samples: 2\n");
+        Ok(())
+    }
+
+    #[test]
+    fn execute_return_parse_errors() {
+        let (mut repl, _, _) = create_repl(&[]);
+
+        let error = repl.execute("(mapp .)").err().unwrap();
+
+        assert!(matches!(error, ReplError::SelectionDiagnostic(_)));
+        let error = repl.execute(r"\set nop").err().unwrap();
+        assert!(matches!(error, ReplError::PreSet(_)));
+    }
+
+    #[test]
+    fn execute_return_error_for_unknown_command() {
+        let (mut repl, _, _) = create_repl(&[]);
+
+        let error = repl.execute(r"\nop").err().unwrap();
+
+        assert!(matches!(error, ReplError::UnknownCommand(_)));
+    }
+
+    #[test]
+    fn execute_quit() -> Result<()> {
+        let (mut repl, _, _) = create_repl(&[]);
+
+        assert_eq!(repl.execute(r"\quit")?, ReplAction::Quit);
+        Ok(())
+    }
+
+    #[test]
+    fn helper_complete_keys_functions_and_input_context() {
+        let (repl, _, _) = create_repl(&[]);
+        let helper = ReplHelper::new(&repl.samples);
+
+        assert_eq!(helper.candidates("", ".na"), vec![".name"]);
+        assert_eq!(helper.candidates("", ".address.c"), vec![".address.city"]);
+        assert_eq!(helper.candidates("", "&file-n"), vec!["&file-name"]);
+        assert_eq!(helper.candidates("(", "flat_m"), vec!["flat_map"]);
+        assert_eq!(helper.candidates("(", ".flat_m"), vec![".flat_map"]);
+        assert!(helper.candidates("", "flat_m").is_empty());
+    }
+}
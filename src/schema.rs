@@ -0,0 +1,419 @@
+use indexmap::{IndexMap, IndexSet};
+
+use crate::{
+    explain::Explanation,
+    json_value::{JsonValue, NumberValue},
+    processor::{Context, Process, ProcessDecision, Result as ProcessResult, Titles},
+};
+
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+const MAX_ENUM_VALUES: usize = 8;
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Default)]
+#[clap(rename_all = "kebab_case")]
+pub enum SchemaFormat {
+    /// A JSON Schema (draft 2020-12) document.
+    #[default]
+    JsonSchema,
+    /// An object with a short description of each path.
+    Tree,
+}
+
+impl SchemaFormat {
+    pub fn explain(&self) -> Explanation {
+        match self {
+            SchemaFormat::JsonSchema => Explanation::new("infer JSON schema"),
+            SchemaFormat::Tree => Explanation::new("infer schema tree"),
+        }
+    }
+
+    pub fn create_process(&self, next: Box<dyn Process>) -> Box<dyn Process> {
+        Box::new(SchemaProcess {
+            format: *self,
+            shape: Shape::default(),
+            next,
+        })
+    }
+}
+
+/// Everything that was observed in one path of the inputs.
+#[derive(Default)]
+struct Shape {
+    count: usize,
+    types: IndexSet<&'static str>,
+    lengths: Option<(usize, usize)>,
+    range: Option<(f64, f64)>,
+    scalars: usize,
+    values: Option<IndexSet<JsonValue>>,
+    objects: usize,
+    properties: IndexMap<String, Shape>,
+    items: Option<Box<Shape>>,
+}
+
+fn type_name(value: &JsonValue) -> &'static str {
+    match value {
+        JsonValue::Null => "null",
+        JsonValue::Boolean(_) => "boolean",
+        JsonValue::String(_) => "string",
+        JsonValue::Number(NumberValue::Float(_)) => "number",
+        JsonValue::Number(_) => "integer",
+        JsonValue::Object(_) => "object",
+        JsonValue::Array(_) => "array",
+    }
+}
+
+fn extend_range<T: PartialOrd + Copy>(range: Option<(T, T)>, value: T) -> Option<(T, T)> {
+    match range {
+        None => Some((value, value)),
+        Some((min, max)) => Some((
+            if value < min { value } else { min },
+            if value > max { value } else { max },
+        )),
+    }
+}
+
+impl Shape {
+    fn add(&mut self, value: &JsonValue) {
+        if self.count == 0 {
+            self.values = Some(IndexSet::new());
+        }
+        self.count += 1;
+        self.types.insert(type_name(value));
+        match value {
+            JsonValue::String(str) => {
+                self.lengths = extend_range(self.lengths, str.chars().count());
+                self.add_scalar(value);
+            }
+            JsonValue::Number(number) => {
+                self.range = extend_range(self.range, number.into());
+                self.add_scalar(value);
+            }
+            JsonValue::Object(map) => {
+                self.objects += 1;
+                for (key, value) in map {
+                    self.properties.entry(key.clone()).or_default().add(value);
+                }
+            }
+            JsonValue::Array(list) => {
+                let items = self.items.get_or_insert_default();
+                for value in list {
+                    items.add(value);
+                }
+            }
+            JsonValue::Null | JsonValue::Boolean(_) => {}
+        }
+    }
+
+    fn add_scalar(&mut self, value: &JsonValue) {
+        self.scalars += 1;
+        if let Some(values) = &mut self.values {
+            values.insert(value.clone());
+            if values.len() > MAX_ENUM_VALUES {
+                self.values = None;
+            }
+        }
+    }
+
+    /// The values, if all of them are strings or numbers, there are few of them, and each was seen at least twice
+    /// on average.
+    fn enum_values(&self) -> Option<&IndexSet<JsonValue>> {
+        if !self
+            .types
+            .iter()
+            .all(|type_name| matches!(*type_name, "null" | "string" | "integer" | "number"))
+        {
+            return None;
+        }
+        self.values
+            .as_ref()
+            .filter(|values| !values.is_empty() && values.len() * 2 <= self.scalars)
+    }
+
+    /// The observed types, where `integer` is dropped if `number` (that includes it) was also observed.
+    fn type_names(&self) -> Vec<&'static str> {
+        let number = self.types.contains("number");
+        self.types
+            .iter()
+            .copied()
+            .filter(|type_name| !number || *type_name != "integer")
+            .collect()
+    }
+
+    fn required(&self) -> Vec<&String> {
+        self.properties
+            .iter()
+            .filter(|(_, property)| property.count == self.objects)
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    fn json_schema(&self) -> IndexMap<String, JsonValue> {
+        let mut schema = IndexMap::new();
+        let types = self.type_names();
+        match types.as_slice() {
+            [] => {}
+            [one] => {
+                schema.insert("type".to_string(), (*one).into());
+            }
+            _ => {
+                let types: Vec<JsonValue> = types.into_iter().map(Into::into).collect();
+                schema.insert("type".to_string(), types.into());
+            }
+        }
+        if let Some(values) = self.enum_values() {
+            let mut values: Vec<JsonValue> = values.iter().cloned().collect();
+            if self.types.contains("null") {
+                values.push(JsonValue::Null);
+            }
+            schema.insert("enum".to_string(), values.into());
+        }
+        if let Some((min, max)) = self.lengths {
+            schema.insert("minLength".to_string(), min.into());
+            schema.insert("maxLength".to_string(), max.into());
+        }
+        if let Some((min, max)) = self.range {
+            schema.insert("minimum".to_string(), min.into());
+            schema.insert("maximum".to_string(), max.into());
+        }
+        if self.objects > 0 {
+            let properties: IndexMap<String, JsonValue> = self
+                .properties
+                .iter()
+                .map(|(key, property)| (key.clone(), property.json_schema().into()))
+                .collect();
+            schema.insert("properties".to_string(), properties.into());
+            let required = self.required();
+            if !required.is_empty() {
+                let required: Vec<JsonValue> = required.into_iter().map(Into::into).collect();
+                schema.insert("required".to_string(), required.into());
+            }
+        }
+        if let Some(items) = &self.items
+            && items.count > 0
+        {
+            schema.insert("items".to_string(), items.json_schema().into());
+        }
+        schema
+    }
+
+    fn tree(&self, path: String, optional: bool, tree: &mut IndexMap<String, JsonValue>) {
+        let mut description = vec![self.type_names().join("|")];
+        if optional {
+            description.push("optional".to_string());
+        }
+        if let Some(values) = self.enum_values() {
+            let values: Vec<String> = values.iter().map(ToString::to_string).collect();
+            description.push(format!("one of {}", values.join(", ")));
+        }
+        if let Some((min, max)) = self.lengths {
+            description.push(format!("length {min}..{max}"));
+        }
+        if let Some((min, max)) = self.range {
+            description.push(format!(
+                "range {}..{}",
+                JsonValue::from(min),
+                JsonValue::from(max)
+            ));
+        }
+        tree.insert(path.clone(), description.join(", ").into());
+        let prefix = if path == "." { "" } else { &path };
+        for (key, property) in &self.properties {
+            let optional = property.count < self.objects;
+            property.tree(format!("{prefix}.{key}"), optional, tree);
+        }
+        if let Some(items) = &self.items
+            && items.count > 0
+        {
+            items.tree(format!("{prefix}[]"), false, tree);
+        }
+    }
+}
+
+struct SchemaProcess {
+    format: SchemaFormat,
+    shape: Shape,
+    next: Box<dyn Process>,
+}
+
+impl Process for SchemaProcess {
+    fn complete(&mut self) -> ProcessResult<()> {
+        let value = match self.format {
+            SchemaFormat::JsonSchema => {
+                let mut schema = IndexMap::new();
+                schema.insert("$schema".to_string(), JSON_SCHEMA_DIALECT.into());
+                schema.extend(self.shape.json_schema());
+                schema.into()
+            }
+            SchemaFormat::Tree => {
+                let mut tree = IndexMap::new();
+                self.shape.tree(".".to_string(), false, &mut tree);
+                tree.into()
+            }
+        };
+        self.next.process(Context::new_with_no_context(value))?;
+        self.next.complete()
+    }
+    fn process(&mut self, context: Context) -> ProcessResult<ProcessDecision> {
+        self.shape.add(&context.build());
+        Ok(ProcessDecision::Continue)
+    }
+    fn start(&mut self, _: Titles) -> ProcessResult<()> {
+        self.next.start(Titles::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::ops::Deref;
+    use std::rc::Rc;
+    use std::str::FromStr;
+
+    use super::*;
+
+    struct Next(Rc<RefCell<Vec<JsonValue>>>);
+    impl Process for Next {
+        fn complete(&mut self) -> ProcessResult<()> {
+            Ok(())
+        }
+        fn process(&mut self, context: Context) -> ProcessResult<ProcessDecision> {
+            self.0.borrow_mut().push(context.input().deref().clone());
+            Ok(ProcessDecision::Continue)
+        }
+        fn start(&mut self, _: Titles) -> ProcessResult<()> {
+            Ok(())
+        }
+    }
+
+    fn infer(format: SchemaFormat, inputs: &[&str]) -> ProcessResult<JsonValue> {
+        let data = Rc::new(RefCell::new(Vec::new()));
+        let mut process = format.create_process(Box::new(Next(data.clone())));
+        process.start(Titles::default())?;
+        for input in inputs {
+            let input = JsonValue::from_str(input).unwrap();
+            process.process(Context::new_with_no_context(input))?;
+        }
+        process.complete()?;
+        let mut data = data.take();
+        assert_eq!(data.len(), 1);
+        Ok(data.remove(0))
+    }
+
+    const INPUTS: [&str; 4] = [
+        r#"{"id": 1, "status": "ok", "tags": ["a"]}"#,
+        r#"{"id": 2, "status": "ok", "tags": [], "score": 1.5}"#,
+        r#"{"id": 3, "status": "error", "tags": ["b", "c"], "score": null}"#,
+        r#"{"id": 4, "status": "ok", "tags": ["a"]}"#,
+    ];
+
+    #[test]
+    fn infer_json_schema() -> ProcessResult<()> {
+        let schema = infer(SchemaFormat::JsonSchema, &INPUTS)?;
+
+        let expected = JsonValue::from_str(
+            r#"{
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "object",
+                "properties": {
+                    "id": {"type": "integer", "minimum": 1, "maximum": 4},
+                    "status": {"type": "string", "enum": ["ok", "error"], "minLength": 2, "maxLength": 5},
+                    "tags": {"type": "array", "items": {"type": "string", "minLength": 1, "maxLength": 1}},
+                    "score": {"type": ["number", "null"], "minimum": 1.5, "maximum": 1.5}
+                },
+                "required": ["id", "status", "tags"]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(schema, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn infer_tree() -> ProcessResult<()> {
+        let tree = infer(SchemaFormat::Tree, &INPUTS)?;
+
+        let expected = JsonValue::from_str(
+            r#"{
+                ".": "object",
+                ".id": "integer, range 1..4",
+                ".status": "string, one of \"ok\", \"error\", length 2..5",
+                ".tags": "array",
+                ".tags[]": "string, length 1..1",
+                ".score": "number|null, optional, range 1.5..1.5"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(tree, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn infer_mixed_types() -> ProcessResult<()> {
+        let schema = infer(SchemaFormat::JsonSchema, &["1", "\"one\"", "[1, \"a\"]"])?;
+
+        let expected = JsonValue::from_str(
+            r#"{
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": ["integer", "string", "array"],
+                "minLength": 3, "maxLength": 3, "minimum": 1, "maximum": 1,
+                "items": {"type": ["integer", "string"], "minLength": 1, "maxLength": 1, "minimum": 1, "maximum": 1}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(schema, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn infer_nothing() -> ProcessResult<()> {
+        let schema = infer(SchemaFormat::JsonSchema, &[])?;
+
+        let expected =
+            JsonValue::from_str(r#"{"$schema": "https://json-schema.org/draft/2020-12/schema"}"#)
+                .unwrap();
+        assert_eq!(schema, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn infer_enum_only_for_strings_and_numbers() -> ProcessResult<()> {
+        let schema = infer(
+            SchemaFormat::JsonSchema,
+            &[r#""ok""#, r#""ok""#, r#""ok""#, "true"],
+        )?;
+
+        let expected = JsonValue::from_str(
+            r#"{
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": ["string", "boolean"],
+                "minLength": 2, "maxLength": 2
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(schema, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn infer_number_for_integers_and_floats() -> ProcessResult<()> {
+        let schema = infer(SchemaFormat::JsonSchema, &["1", "2.5", "null", "1", "2.5"])?;
+
+        let expected = JsonValue::from_str(
+            r#"{
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": ["number", "null"],
+                "enum": [1, 2.5, null],
+                "minimum": 1, "maximum": 2.5
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(schema, expected);
+
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
 use crate::const_getter::ConstGetters;
+use crate::explain::Explanation;
 use crate::extractor::parse_extractor;
 use crate::extractor::root;
+use crate::functions_definitions::FunctionDefinitions;
 use crate::functions_definitions::FunctionDefinitionsError;
 use crate::functions_definitions::find_function;
 use crate::input_context_extractor::InputContextExtractorParseError;
@@ -15,8 +17,11 @@ use crate::processor::Titles;
 use crate::reader::Location;
 use crate::reader::Reader;
 use crate::reader::from_string;
+use crate::selection_diagnostic::SelectionDiagnostic;
 use crate::selection_extractor::parse_get_selection;
+use crate::strict::EvaluationError;
 use crate::variables_extractor::parse_get_variable;
+use std::cell::{OnceCell, RefCell};
 use std::io::Error as IoError;
 use std::io::Read;
 use std::num::ParseIntError;
@@ -28,6 +33,13 @@ use thiserror::Error;
 
 pub trait Get {
     fn get(&self, value: &Context) -> Option<JsonValue>;
+    fn explain(&self) -> Explanation {
+        Explanation::new("(opaque)")
+    }
+    /// Whether the original text of the input is needed (for `&raw`), so it should be kept while reading.
+    fn uses_raw_input(&self) -> bool {
+        false
+    }
 }
 
 #[derive(Clone)]
@@ -50,8 +62,12 @@ pub enum SelectionParseError {
     NumberParseError(#[from] ParseIntError),
     #[error("{0}")]
     Function(#[from] FunctionDefinitionsError),
+    #[error("{0}: {1}")]
+    FunctionCall(Location, FunctionDefinitionsError),
     #[error("{0}")]
     InputContext(#[from] InputContextExtractorParseError),
+    #[error("{0}: {1}")]
+    InputContextName(Location, InputContextExtractorParseError),
     #[error("{0}, Missing key name")]
     MissingKey(Location),
     #[error("{0}: Expecting equals, got {1}")]
@@ -62,6 +78,24 @@ pub enum SelectionParseError {
     UnexpectedEof,
 }
 
+impl SelectionParseError {
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            SelectionParseError::JsonError(error) => error.location(),
+            SelectionParseError::FunctionCall(location, _)
+            | SelectionParseError::InputContextName(location, _)
+            | SelectionParseError::MissingKey(location)
+            | SelectionParseError::ExpectingEquals(location, _)
+            | SelectionParseError::ExpectingEof(location, _) => Some(location),
+            _ => None,
+        }
+    }
+
+    pub fn with_source(self, source: &str) -> SelectionDiagnostic {
+        SelectionDiagnostic::new(source, self)
+    }
+}
+
 impl FromStr for Selection {
     type Err = SelectionParseError;
     fn from_str(s: &str) -> Result<Self> {
@@ -135,13 +169,15 @@ pub fn read_getter<R: Read>(reader: &mut Reader<R>) -> Result<Rc<dyn Get>> {
 }
 
 fn parse_function<R: Read>(reader: &mut Reader<R>) -> Result<Rc<dyn Get>> {
+    let location = reader.where_am_i();
     let mut name = read_function_name(reader)?;
     let mut args: Vec<Rc<dyn Get>> = Vec::new();
     if name.starts_with('.') {
         args.push(root());
         name = name[1..].to_string();
     }
-    let function = find_function(&name)?;
+    let function =
+        find_function(&name).map_err(|e| SelectionParseError::FunctionCall(location.clone(), e))?;
     loop {
         reader.eat_whitespace()?;
         match reader.peek()? {
@@ -161,8 +197,137 @@ fn parse_function<R: Read>(reader: &mut Reader<R>) -> Result<Rc<dyn Get>> {
         }
     }
     reader.next()?;
-    let fun = function.create(args)?;
-    Ok(fun)
+    let getter = function
+        .create(args.clone())
+        .map_err(|e| SelectionParseError::FunctionCall(location, e))?;
+    Ok(Rc::new(FunctionCall {
+        function,
+        args,
+        getter,
+        strict: OnceCell::new(),
+    }))
+}
+
+/// A function argument that keeps its last value, so the reason for a function that produced nothing can be
+/// explained without evaluating the arguments again.
+struct RecordedArgument {
+    arg: Rc<dyn Get>,
+    value: RefCell<Option<Option<JsonValue>>>,
+}
+
+impl Get for RecordedArgument {
+    fn get(&self, value: &Context) -> Option<JsonValue> {
+        let result = self.arg.get(value);
+        *self.value.borrow_mut() = Some(result.clone());
+        result
+    }
+}
+
+/// The function created over recorded arguments, only built once it is evaluated with `--strict`.
+struct StrictCall {
+    args: Vec<Rc<RecordedArgument>>,
+    getter: Rc<dyn Get>,
+}
+
+struct FunctionCall {
+    function: &'static FunctionDefinitions,
+    args: Vec<Rc<dyn Get>>,
+    getter: Rc<dyn Get>,
+    strict: OnceCell<Option<StrictCall>>,
+}
+
+impl FunctionCall {
+    fn strict_call(&self) -> Option<&StrictCall> {
+        self.strict
+            .get_or_init(|| {
+                let args: Vec<Rc<RecordedArgument>> = self
+                    .args
+                    .iter()
+                    .map(|arg| {
+                        Rc::new(RecordedArgument {
+                            arg: arg.clone(),
+                            value: RefCell::default(),
+                        })
+                    })
+                    .collect();
+                let getter = self
+                    .function
+                    .create(args.iter().map(|arg| arg.clone() as Rc<dyn Get>).collect())
+                    .ok()?;
+                Some(StrictCall { args, getter })
+            })
+            .as_ref()
+    }
+
+    fn explain_nothing(&self, strict: &StrictCall) -> EvaluationError {
+        // Arguments that were not evaluated (for example by `if` or `or`) are ignored.
+        let values: Vec<_> = strict.args.iter().map(|arg| arg.value.take()).collect();
+        let mut reason = None;
+        for (index, value) in values.iter().enumerate() {
+            match value {
+                None => {}
+                Some(None) => {
+                    reason = Some(format!("argument {index} produced nothing"));
+                    break;
+                }
+                Some(Some(value)) => {
+                    let expected = self.function.expected_types(index);
+                    let actual = value.type_name();
+                    if !expected.is_empty() && !expected.contains(&actual.as_str()) {
+                        reason = Some(format!(
+                            "argument {index} expected {}, got {actual}",
+                            expected.join(" or ")
+                        ));
+                        break;
+                    }
+                }
+            }
+        }
+        let reason = reason.unwrap_or_else(|| {
+            let types: Vec<_> = values
+                .iter()
+                .flatten()
+                .flatten()
+                .map(JsonValue::type_name)
+                .collect();
+            format!("the arguments were ({})", types.join(", "))
+        });
+        EvaluationError::FunctionReturnedNothing {
+            function: self.function.name(),
+            signature: self.function.signature(),
+            reason,
+        }
+    }
+}
+
+impl Get for FunctionCall {
+    fn get(&self, value: &Context) -> Option<JsonValue> {
+        let Some(strict_errors) = value.strict_errors() else {
+            return self.getter.get(value);
+        };
+        let Some(strict) = self.strict_call() else {
+            return self.getter.get(value);
+        };
+        let mark = strict_errors.mark();
+        for arg in &strict.args {
+            arg.value.take();
+        }
+        let result = strict.getter.get(value);
+        if result.is_some() {
+            strict_errors.discard_since(mark);
+        } else if !strict_errors.has_errors_since(mark) {
+            let error = self.explain_nothing(strict);
+            value.report(|| error);
+        }
+        result
+    }
+    fn explain(&self) -> Explanation {
+        Explanation::new(format!("function `{}`", self.function.name()))
+            .with_children(self.args.iter().map(|arg| arg.explain()))
+    }
+    fn uses_raw_input(&self) -> bool {
+        self.args.iter().any(|arg| arg.uses_raw_input())
+    }
 }
 
 fn read_function_name<R: Read>(reader: &mut Reader<R>) -> Result<String> {
@@ -191,6 +356,10 @@ fn read_function_name<R: Read>(reader: &mut Reader<R>) -> Result<String> {
 }
 
 impl Selection {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
     pub fn create_process(&self, next: Box<dyn Process>) -> Box<dyn Process> {
         let process = SelectionProcess {
             name: self.name.clone(),
@@ -205,4 +374,10 @@ impl Get for Selection {
     fn get(&self, context: &Context) -> Option<JsonValue> {
         self.getter.get(context)
     }
+    fn explain(&self) -> Explanation {
+        Explanation::new(format!("select `{}`", self.name)).with_child(self.getter.explain())
+    }
+    fn uses_raw_input(&self) -> bool {
+        self.getter.uses_raw_input()
+    }
 }
@@ -0,0 +1,262 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    functions_definitions::{FunctionDefinitionsError, all_function_names},
+    input_context_extractor::{InputContextExtractorParseError, input_context_names},
+    selection::SelectionParseError,
+};
+
+const MAX_SUGGESTIONS: usize = 3;
+
+/// A selection parse error together with the selection text, so it can be displayed with a caret under the
+/// offending column, the enclosing function call and a list of suggestions.
+#[derive(Debug)]
+pub struct SelectionDiagnostic {
+    text: String,
+    error: Box<SelectionParseError>,
+}
+
+impl SelectionDiagnostic {
+    pub fn new(text: &str, error: SelectionParseError) -> Self {
+        SelectionDiagnostic {
+            text: text.to_string(),
+            error: Box::new(error),
+        }
+    }
+
+    fn offset(&self) -> usize {
+        let Some(location) = self.error.location() else {
+            return self.text.len();
+        };
+        let line_start: usize = self
+            .text
+            .split_inclusive('\n')
+            .take(location.line_number.saturating_sub(1))
+            .map(str::len)
+            .sum();
+        (line_start + location.char_number.saturating_sub(2)).min(self.text.len())
+    }
+
+    fn underline_length(&self, offset: usize) -> usize {
+        match self.error.as_ref() {
+            SelectionParseError::FunctionCall(_, _)
+            | SelectionParseError::InputContextName(_, _) => {
+                1 + token_at(&self.text, offset + 1).chars().count()
+            }
+            _ => 1,
+        }
+    }
+
+    fn suggestions(&self) -> Vec<&'static str> {
+        match self.error.as_ref() {
+            SelectionParseError::FunctionCall(
+                _,
+                FunctionDefinitionsError::UnknownFunction(name),
+            ) => closest(name, all_function_names()),
+            SelectionParseError::InputContextName(
+                _,
+                InputContextExtractorParseError::UnknownType(name),
+            ) => closest(name, input_context_names()),
+            _ => vec![],
+        }
+    }
+}
+
+impl Display for SelectionDiagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "{}", self.error)?;
+        let offset = self.offset();
+        let line_start = self.text[..offset].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = self.text[offset..]
+            .find('\n')
+            .map_or(self.text.len(), |index| offset + index);
+        writeln!(f, "    {}", &self.text[line_start..line_end])?;
+        write!(
+            f,
+            "    {}{}",
+            " ".repeat(self.text[line_start..offset].chars().count()),
+            "^".repeat(self.underline_length(offset))
+        )?;
+        if let Some(function) = enclosing_function(&self.text, offset) {
+            write!(f, "\n    in the call to `{function}`")?;
+        }
+        let suggestions = self.suggestions();
+        if !suggestions.is_empty() {
+            let suggestions: Vec<_> = suggestions.iter().map(|s| format!("`{s}`")).collect();
+            write!(f, "\n    did you mean {}?", suggestions.join(" or "))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for SelectionDiagnostic {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.error.as_ref())
+    }
+}
+
+fn is_delimiter(ch: char) -> bool {
+    ch.is_whitespace() || ch == ',' || ch == '(' || ch == ')'
+}
+
+fn token_at(text: &str, offset: usize) -> &str {
+    let Some(rest) = text.get(offset..) else {
+        return "";
+    };
+    let end = rest.find(is_delimiter).unwrap_or(rest.len());
+    &rest[..end]
+}
+
+fn enclosing_function(text: &str, offset: usize) -> Option<&str> {
+    let mut open_calls = vec![];
+    let mut in_string = false;
+    let mut escaped = false;
+    for (index, ch) in text[..offset].char_indices() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if ch == '\\' {
+                escaped = true;
+            } else if ch == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match ch {
+            '"' => in_string = true,
+            '(' => open_calls.push(index),
+            ')' => {
+                open_calls.pop();
+            }
+            _ => {}
+        }
+    }
+    let start = open_calls.pop()?;
+    let name = token_at(text, start + 1);
+    let name = name.strip_prefix('.').unwrap_or(name);
+    if name.is_empty() { None } else { Some(name) }
+}
+
+fn closest(name: &str, candidates: impl Iterator<Item = &'static str>) -> Vec<&'static str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+    let name = name.to_lowercase();
+    let mut found: Vec<_> = candidates
+        .map(|candidate| (edit_distance(&name, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+    found.sort();
+    found.dedup();
+    found
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+fn edit_distance(one: &str, two: &str) -> usize {
+    let one: Vec<char> = one.chars().collect();
+    let two: Vec<char> = two.chars().collect();
+    let mut distances = vec![vec![0; two.len() + 1]; one.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+    for i in 1..=one.len() {
+        for j in 1..=two.len() {
+            let cost = usize::from(one[i - 1] != two[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && one[i - 1] == two[j - 2] && one[i - 2] == two[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    distances[one.len()][two.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::selection::Selection;
+
+    fn diagnostic(text: &str) -> String {
+        let error = Selection::from_str(text).err().unwrap();
+        error.with_source(text).to_string()
+    }
+
+    #[test]
+    fn edit_distance_count_the_changes() {
+        assert_eq!(edit_distance("map", "map"), 0);
+        assert_eq!(edit_distance("mapp", "map"), 1);
+        assert_eq!(edit_distance("lenght", "length"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn closest_return_the_nearest_names() {
+        let names = ["map", "flat_map", "max", "len"];
+
+        assert_eq!(closest("mapp", names.into_iter()), vec!["map"]);
+        assert!(closest("something", names.into_iter()).is_empty());
+    }
+
+    #[test]
+    fn unknown_function_show_caret_enclosing_call_and_suggestions() {
+        let text = diagnostic("(filter (lenn .) .)");
+
+        let lines: Vec<_> = text.lines().collect();
+        assert!(lines[0].ends_with("Function 'lenn' is unknown"));
+        assert_eq!(lines[1], "    (filter (lenn .) .)");
+        assert_eq!(lines[2], "            ^^^^^");
+        assert_eq!(lines[3], "    in the call to `filter`");
+        assert!(lines[4].starts_with("    did you mean `len`"));
+    }
+
+    #[test]
+    fn unknown_input_context_show_suggestions() {
+        let text = diagnostic("&idnex");
+
+        let lines: Vec<_> = text.lines().collect();
+        assert_eq!(lines[2], "    ^^^^^^");
+        assert_eq!(lines[3], "    did you mean `index`?");
+    }
+
+    #[test]
+    fn arity_error_show_the_signature() {
+        let text = diagnostic("(map (size))");
+
+        let lines: Vec<_> = text.lines().collect();
+        assert!(lines[0].ends_with(
+            "Missing argument for size, got only 0 needed at least 1, usage: (size <arg0>)"
+        ));
+        assert_eq!(lines[2], "         ^^^^^");
+        assert_eq!(lines[3], "    in the call to `map`");
+    }
+
+    #[test]
+    fn unexpected_eof_point_to_the_end() {
+        let text = diagnostic("(map (len .)");
+
+        let lines: Vec<_> = text.lines().collect();
+        assert_eq!(lines[0], "Unexpected end of string");
+        assert_eq!(lines[2], "                ^");
+        assert_eq!(lines[3], "    in the call to `map`");
+    }
+
+    #[test]
+    fn expecting_eof_point_to_the_extra_character() {
+        let error = crate::filter::Filter::from_str("(len .))").err().unwrap();
+        let text = error.with_source("(len .))").to_string();
+
+        let lines: Vec<_> = text.lines().collect();
+        assert_eq!(lines[2], "           ^");
+        assert_eq!(lines.len(), 3);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    json_parser::JsonParserError, json_value::JsonValue, processor::Context, reader::Reader,
-    selection::Get, selection::Result,
+    explain::Explanation, json_parser::JsonParserError, json_value::JsonValue, processor::Context,
+    reader::Reader, selection::Get, selection::Result, strict::EvaluationError,
 };
 use std::{io::Read, rc::Rc};
 
@@ -10,8 +10,14 @@ struct SelectionExtructor {
 
 impl Get for SelectionExtructor {
     fn get(&self, value: &Context) -> Option<JsonValue> {
+        if !value.has_selection(&self.name) {
+            value.report(|| EvaluationError::UnknownSelection(self.name.clone()));
+        }
         value.get_selected(&self.name)
     }
+    fn explain(&self) -> Explanation {
+        Explanation::new(format!("selection `/{}/`", self.name))
+    }
 }
 
 pub fn parse_get_selection<R: Read>(reader: &mut Reader<R>) -> Result<Rc<dyn Get>> {
@@ -149,6 +149,16 @@ fn build_help() -> Vec<SelectionHelp> {
                 "* `&ended-at-line-number` - To get the line number within the input file in which the input ended.",
                 "* `&ended-at-char-number` - To get the char number within the line within the input file in which the input ended.",
                 "* `&file-name` - To get the name of the input file from which the input was parsed (will be empty for stdin input).",
+                "* `&raw` - To get the original text of the input, as it was in the input file.",
+                "* `&byte-offset-start` - To get the offset in bytes within the input file in which the input started.",
+                "* `&byte-offset-end` - To get the offset in bytes within the input file in which the input ended (the offset of the first byte after the input).",
+                "* `&file-path` - To get the absolute path of the input file (will be empty for stdin input, like all the file details).",
+                "* `&file-dir` - To get the absolute path of the directory of the input file.",
+                "* `&file-stem` - To get the name of the input file without the extension.",
+                "* `&file-extension` - To get the extension of the input file.",
+                "* `&file-size` - To get the size in bytes of the input file.",
+                "* `&file-modified-time` - To get the last modification time of the input file, in seconds since epoch (see `format_time`).",
+                "* `&file-index` - To get the index of the input file within the input files of the current run.",
             ],
         ).with_example(
             UsageExample::new(
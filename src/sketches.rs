@@ -0,0 +1,283 @@
+use std::{
+    collections::{BTreeMap, hash_map::DefaultHasher},
+    hash::{Hash, Hasher},
+};
+
+use indexmap::IndexMap;
+
+use crate::json_value::JsonValue;
+
+const HYPER_LOG_LOG_BITS: u32 = 12;
+
+/// Estimate the number of distinct values, in constant memory (HyperLogLog, with about 1.6% standard error).
+pub struct DistinctCounter {
+    registers: Vec<u8>,
+}
+
+impl Default for DistinctCounter {
+    fn default() -> Self {
+        DistinctCounter {
+            registers: vec![0; 1 << HYPER_LOG_LOG_BITS],
+        }
+    }
+}
+
+impl DistinctCounter {
+    pub fn add<T: Hash>(&mut self, value: &T) {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        let hash = hasher.finish();
+        let index = (hash >> (64 - HYPER_LOG_LOG_BITS)) as usize;
+        let rest = hash << HYPER_LOG_LOG_BITS;
+        let rank = (rest.leading_zeros().min(64 - HYPER_LOG_LOG_BITS) + 1) as u8;
+        self.registers[index] = self.registers[index].max(rank);
+    }
+
+    pub fn estimate(&self) -> u64 {
+        let size = self.registers.len() as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / size);
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|register| 2f64.powi(-(*register as i32)))
+            .sum();
+        let estimate = alpha * size * size / sum;
+        let zeros = self.registers.iter().filter(|r| **r == 0).count();
+        if estimate <= 2.5 * size && zeros > 0 {
+            // Linear counting is more accurate for small numbers of values.
+            (size * (size / zeros as f64).ln()).round() as u64
+        } else {
+            estimate.round() as u64
+        }
+    }
+}
+
+/// Estimate quantiles of a stream of numbers, with a bounded relative error (a DDSketch).
+///
+/// Numbers are counted in buckets whose bounds grow exponentially, so any quantile is reported within
+/// `RELATIVE_ACCURACY` of its real value, regardless of the number of values. Up to `EXACT_LIMIT` numbers
+/// are kept as is, so small streams get exact quantiles.
+pub struct QuantileSketch {
+    exact: Option<Vec<f64>>,
+    gamma: f64,
+    positive: BTreeMap<i32, u64>,
+    negative: BTreeMap<i32, u64>,
+    zeros: u64,
+    count: u64,
+}
+
+const RELATIVE_ACCURACY: f64 = 0.01;
+const EXACT_LIMIT: usize = 1024;
+
+impl Default for QuantileSketch {
+    fn default() -> Self {
+        QuantileSketch {
+            exact: Some(Vec::new()),
+            gamma: (1.0 + RELATIVE_ACCURACY) / (1.0 - RELATIVE_ACCURACY),
+            positive: BTreeMap::new(),
+            negative: BTreeMap::new(),
+            zeros: 0,
+            count: 0,
+        }
+    }
+}
+
+impl QuantileSketch {
+    pub fn add(&mut self, value: f64) {
+        if !value.is_finite() {
+            return;
+        }
+        if let Some(exact) = &mut self.exact {
+            if exact.len() < EXACT_LIMIT {
+                exact.push(value);
+                return;
+            }
+            for value in self.exact.take().unwrap_or_default() {
+                self.add_to_bucket(value);
+            }
+        }
+        self.add_to_bucket(value);
+    }
+
+    fn add_to_bucket(&mut self, value: f64) {
+        self.count += 1;
+        if value.abs() < f64::MIN_POSITIVE {
+            self.zeros += 1;
+        } else if value > 0.0 {
+            *self.positive.entry(self.bucket(value)).or_default() += 1;
+        } else {
+            *self.negative.entry(self.bucket(-value)).or_default() += 1;
+        }
+    }
+
+    /// The value at the quantile (between 0 and 1), or nothing if no value was added.
+    pub fn quantile(&self, quantile: f64) -> Option<f64> {
+        if let Some(exact) = &self.exact {
+            if exact.is_empty() {
+                return None;
+            }
+            let mut sorted = exact.clone();
+            sorted.sort_by(f64::total_cmp);
+            let rank = (quantile * (sorted.len() - 1) as f64).round() as usize;
+            return Some(sorted[rank]);
+        }
+        if self.count == 0 {
+            return None;
+        }
+        let rank = (quantile * (self.count - 1) as f64).round() as u64;
+        let mut seen = 0;
+        for (bucket, count) in self.negative.iter().rev() {
+            seen += count;
+            if seen > rank {
+                return Some(-self.value(*bucket));
+            }
+        }
+        seen += self.zeros;
+        if seen > rank {
+            return Some(0.0);
+        }
+        for (bucket, count) in &self.positive {
+            seen += count;
+            if seen > rank {
+                return Some(self.value(*bucket));
+            }
+        }
+        None
+    }
+
+    fn bucket(&self, value: f64) -> i32 {
+        value.log(self.gamma).ceil() as i32
+    }
+
+    fn value(&self, bucket: i32) -> f64 {
+        2.0 * self.gamma.powi(bucket) / (self.gamma + 1.0)
+    }
+}
+
+/// Find the most frequent values in constant memory (the Space-Saving algorithm).
+///
+/// Up to `capacity` values are counted; when a new value arrives and there is no room, it replaces the least
+/// frequent value and inherits its count, so the counts of the top values may be overestimated.
+pub struct TopValues {
+    capacity: usize,
+    counts: IndexMap<JsonValue, u64>,
+}
+
+impl TopValues {
+    pub fn new(capacity: usize) -> Self {
+        TopValues {
+            capacity,
+            counts: IndexMap::with_capacity(capacity),
+        }
+    }
+
+    pub fn add(&mut self, value: &JsonValue) {
+        if let Some(count) = self.counts.get_mut(value) {
+            *count += 1;
+        } else if self.counts.len() < self.capacity {
+            self.counts.insert(value.clone(), 1);
+        } else if let Some((index, min)) = self
+            .counts
+            .values()
+            .copied()
+            .enumerate()
+            .min_by_key(|(_, count)| *count)
+        {
+            self.counts.swap_remove_index(index);
+            self.counts.insert(value.clone(), min + 1);
+        }
+    }
+
+    /// The `k` most frequent values with their counts, the most frequent first.
+    pub fn top(&self, k: usize) -> Vec<(&JsonValue, u64)> {
+        let mut top: Vec<_> = self
+            .counts
+            .iter()
+            .map(|(value, count)| (value, *count))
+            .collect();
+        top.sort_by(|(_, one), (_, two)| two.cmp(one));
+        top.truncate(k);
+        top
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distinct_counter_estimate_the_number_of_distinct_values() {
+        let mut counter = DistinctCounter::default();
+        for i in 0..10_000u64 {
+            counter.add(&(i % 2_000));
+        }
+
+        let estimate = counter.estimate() as f64;
+
+        assert!((estimate - 2_000.0).abs() < 2_000.0 * 0.05, "{estimate}");
+    }
+
+    #[test]
+    fn distinct_counter_is_exact_for_few_values() {
+        let mut counter = DistinctCounter::default();
+        for value in ["a", "b", "a", "c"] {
+            counter.add(&value);
+        }
+
+        assert_eq!(counter.estimate(), 3);
+    }
+
+    #[test]
+    fn quantile_sketch_is_exact_for_few_values() {
+        let mut sketch = QuantileSketch::default();
+        for value in [3.0, 1.0, 2.0] {
+            sketch.add(value);
+        }
+
+        assert_eq!(sketch.quantile(0.5), Some(2.0));
+        assert_eq!(sketch.quantile(0.9), Some(3.0));
+        assert_eq!(QuantileSketch::default().quantile(0.5), None);
+    }
+
+    #[test]
+    fn quantile_sketch_is_within_the_relative_accuracy() {
+        let mut sketch = QuantileSketch::default();
+        for i in 1..=10_000 {
+            sketch.add(i as f64);
+        }
+
+        for (quantile, expected) in [(0.5, 5_000.0), (0.9, 9_000.0), (0.99, 9_900.0)] {
+            let value = sketch.quantile(quantile).unwrap();
+            assert!(
+                (value - expected).abs() <= expected * 0.021,
+                "{quantile}: {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn quantile_sketch_handle_negative_numbers_and_zeros() {
+        let mut sketch = QuantileSketch::default();
+        for value in [-10.0, 0.0, 0.0, 10.0, 20.0] {
+            sketch.add_to_bucket(value);
+        }
+        sketch.exact = None;
+
+        assert!((sketch.quantile(0.0).unwrap() + 10.0).abs() < 0.2);
+        assert_eq!(sketch.quantile(0.5), Some(0.0));
+        assert!((sketch.quantile(1.0).unwrap() - 20.0).abs() < 0.4);
+    }
+
+    #[test]
+    fn top_values_keep_the_most_frequent_values() {
+        let mut top = TopValues::new(3);
+        for value in ["a", "b", "a", "c", "a", "b", "d", "a"] {
+            top.add(&value.into());
+        }
+
+        let top = top.top(2);
+
+        assert_eq!(top[0], (&"a".into(), 4));
+        assert_eq!(top[1].1, 2);
+    }
+}
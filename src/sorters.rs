@@ -5,6 +5,7 @@ use std::str::FromStr;
 
 use thiserror::Error;
 
+use crate::explain::Explanation;
 use crate::json_value::JsonValue;
 use crate::processor::{Context, Process, ProcessDecision, Result as ProcessResult, Titles};
 use crate::{
@@ -68,6 +69,18 @@ fn read_to_eof<R: Read>(r: &mut Reader<R>) -> Result<String, SelectionParseError
 }
 
 impl Sorter {
+    pub fn explain(&self) -> Explanation {
+        let direction = match self.direction {
+            Direction::Asc => "ascending",
+            Direction::Desc => "descending",
+        };
+        Explanation::new(format!("sort by ({direction})")).with_child(self.sort_by.explain())
+    }
+
+    pub fn uses_raw_input(&self) -> bool {
+        self.sort_by.uses_raw_input()
+    }
+
     pub fn create_processor(
         &self,
         next: Box<dyn Process>,
@@ -1,6 +1,7 @@
 use std::{rc::Rc, str::FromStr};
 
 use crate::{
+    explain::Explanation,
     json_value::JsonValue,
     processor::{Context, Process, ProcessDecision, Result as ProcessResult, Titles},
     reader::from_string,
@@ -31,6 +32,14 @@ impl FromStr for Splitter {
 }
 
 impl Splitter {
+    pub fn explain(&self) -> Explanation {
+        Explanation::new("split by").with_child(self.split_by.explain())
+    }
+
+    pub fn uses_raw_input(&self) -> bool {
+        self.split_by.uses_raw_input()
+    }
+
     pub fn create_process(&self, next: Box<dyn Process>) -> Box<dyn Process> {
         Box::new(SplitterProcess {
             next,
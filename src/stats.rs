@@ -0,0 +1,237 @@
+use indexmap::IndexMap;
+
+use crate::{
+    explain::Explanation,
+    json_value::JsonValue,
+    processor::{Context, Process, ProcessDecision, Result as ProcessResult, Titles},
+    selection::{Get, Selection},
+    sketches::{DistinctCounter, QuantileSketch, TopValues},
+};
+
+const PERCENTILES: [(&str, f64); 3] = [("p50", 0.5), ("p90", 0.9), ("p99", 0.99)];
+const TOP_VALUES: usize = 5;
+const TOP_VALUES_CAPACITY: usize = 100;
+
+pub struct Stats {
+    selections: Vec<Selection>,
+}
+
+impl Stats {
+    pub fn new(selections: Vec<Selection>) -> Self {
+        Stats { selections }
+    }
+
+    pub fn explain(&self) -> Explanation {
+        Explanation::new("statistics").with_children(self.selections.iter().map(Get::explain))
+    }
+
+    pub fn uses_raw_input(&self) -> bool {
+        self.selections.iter().any(Get::uses_raw_input)
+    }
+
+    pub fn create_process(&self, next: Box<dyn Process>) -> Box<dyn Process> {
+        let fields = self
+            .selections
+            .iter()
+            .map(|selection| (selection.clone(), FieldStats::default()))
+            .collect();
+        Box::new(StatsProcess { fields, next })
+    }
+}
+
+struct FieldStats {
+    count: usize,
+    nulls: usize,
+    distinct: DistinctCounter,
+    numbers: usize,
+    min: Option<f64>,
+    max: Option<f64>,
+    mean: f64,
+    squares: f64,
+    quantiles: QuantileSketch,
+    top: TopValues,
+}
+
+impl Default for FieldStats {
+    fn default() -> Self {
+        FieldStats {
+            count: 0,
+            nulls: 0,
+            distinct: DistinctCounter::default(),
+            numbers: 0,
+            min: None,
+            max: None,
+            mean: 0.0,
+            squares: 0.0,
+            quantiles: QuantileSketch::default(),
+            top: TopValues::new(TOP_VALUES_CAPACITY),
+        }
+    }
+}
+
+impl FieldStats {
+    fn add(&mut self, value: Option<JsonValue>) {
+        self.count += 1;
+        let value = match value {
+            None | Some(JsonValue::Null) => {
+                self.nulls += 1;
+                return;
+            }
+            Some(value) => value,
+        };
+        self.distinct.add(&value);
+        self.top.add(&value);
+        if let JsonValue::Number(number) = &value {
+            let number: f64 = number.into();
+            self.numbers += 1;
+            self.min = Some(self.min.map_or(number, |min| min.min(number)));
+            self.max = Some(self.max.map_or(number, |max| max.max(number)));
+            // Welford's online algorithm, to avoid losing precision with large sums.
+            let delta = number - self.mean;
+            self.mean += delta / self.numbers as f64;
+            self.squares += delta * (number - self.mean);
+            self.quantiles.add(number);
+        }
+    }
+
+    fn summary(&self) -> JsonValue {
+        let mut summary = IndexMap::new();
+        summary.insert("count".to_string(), self.count.into());
+        summary.insert("null_count".to_string(), self.nulls.into());
+        summary.insert(
+            "distinct".to_string(),
+            (self.distinct.estimate() as usize).into(),
+        );
+        if let (Some(min), Some(max)) = (self.min, self.max) {
+            summary.insert("min".to_string(), min.into());
+            summary.insert("max".to_string(), max.into());
+            summary.insert("mean".to_string(), self.mean.into());
+            let variance = self.squares / self.numbers as f64;
+            summary.insert("stddev".to_string(), variance.sqrt().into());
+            for (name, quantile) in PERCENTILES {
+                if let Some(value) = self.quantiles.quantile(quantile) {
+                    summary.insert(name.to_string(), value.into());
+                }
+            }
+        }
+        let top: Vec<JsonValue> = self
+            .top
+            .top(TOP_VALUES)
+            .into_iter()
+            .map(|(value, count)| {
+                let mut top = IndexMap::new();
+                top.insert("value".to_string(), value.clone());
+                top.insert("count".to_string(), (count as usize).into());
+                top.into()
+            })
+            .collect();
+        summary.insert("top".to_string(), top.into());
+        summary.into()
+    }
+}
+
+struct StatsProcess {
+    fields: Vec<(Selection, FieldStats)>,
+    next: Box<dyn Process>,
+}
+
+impl Process for StatsProcess {
+    fn complete(&mut self) -> ProcessResult<()> {
+        let mut data = IndexMap::new();
+        for (selection, stats) in &self.fields {
+            data.insert(selection.name().to_string(), stats.summary());
+        }
+        let context = Context::new_with_no_context(data.into());
+        self.next.process(context)?;
+        self.next.complete()
+    }
+    fn process(&mut self, context: Context) -> ProcessResult<ProcessDecision> {
+        for (selection, stats) in &mut self.fields {
+            stats.add(selection.get(&context));
+        }
+        Ok(ProcessDecision::Continue)
+    }
+    fn start(&mut self, _: Titles) -> ProcessResult<()> {
+        self.next.start(Titles::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::ops::Deref;
+    use std::rc::Rc;
+    use std::str::FromStr;
+
+    use super::*;
+
+    struct Next(Rc<RefCell<Vec<JsonValue>>>);
+    impl Process for Next {
+        fn complete(&mut self) -> ProcessResult<()> {
+            Ok(())
+        }
+        fn process(&mut self, context: Context) -> ProcessResult<ProcessDecision> {
+            self.0.borrow_mut().push(context.input().deref().clone());
+            Ok(ProcessDecision::Continue)
+        }
+        fn start(&mut self, _: Titles) -> ProcessResult<()> {
+            Ok(())
+        }
+    }
+
+    fn run(selections: &[&str], inputs: &[&str]) -> ProcessResult<Vec<JsonValue>> {
+        let data = Rc::new(RefCell::new(Vec::new()));
+        let selections = selections
+            .iter()
+            .map(|selection| Selection::from_str(selection).unwrap())
+            .collect();
+        let mut process = Stats::new(selections).create_process(Box::new(Next(data.clone())));
+        process.start(Titles::default())?;
+        for input in inputs {
+            let input = JsonValue::from_str(input).unwrap();
+            process.process(Context::new_with_no_context(input))?;
+        }
+        process.complete()?;
+        Ok(data.take())
+    }
+
+    #[test]
+    fn complete_send_a_single_summary() -> ProcessResult<()> {
+        let output = run(
+            &[".size", ".name"],
+            &[
+                r#"{"size": 1, "name": "a"}"#,
+                r#"{"size": 2, "name": "b"}"#,
+                r#"{"size": 3, "name": "a"}"#,
+                r#"{"size": null}"#,
+            ],
+        )?;
+
+        let expected = JsonValue::from_str(
+            r#"{
+                ".size": {"count": 4, "null_count": 1, "distinct": 3, "min": 1, "max": 3, "mean": 2,
+                    "stddev": 0.816496580927726, "p50": 2, "p90": 3, "p99": 3,
+                    "top": [{"value": 1, "count": 1}, {"value": 2, "count": 1}, {"value": 3, "count": 1}]},
+                ".name": {"count": 4, "null_count": 1, "distinct": 2,
+                    "top": [{"value": "a", "count": 2}, {"value": "b", "count": 1}]}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(output, vec![expected]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn complete_send_a_summary_without_inputs() -> ProcessResult<()> {
+        let output = run(&[".size=size"], &[])?;
+
+        let expected = JsonValue::from_str(
+            r#"{"size": {"count": 0, "null_count": 0, "distinct": 0, "top": []}}"#,
+        )
+        .unwrap();
+        assert_eq!(output, vec![expected]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,372 @@
+use std::{io::Read, str::FromStr};
+
+use thiserror::Error;
+
+use crate::{
+    explain::Explanation,
+    json_parser::{JsonParser, JsonParserError, Result},
+    json_value::JsonValue,
+    reader::{RECORD_SEPARATOR, Reader},
+};
+
+#[derive(Debug, Error)]
+pub enum StreamSplitterParseError {
+    #[error("Stream path `{0}` should start with a `.`")]
+    MissingDot(String),
+    #[error("Stream path `{0}` has an empty key")]
+    EmptyKey(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum State {
+    /// Waiting for the next top level value.
+    Start,
+    /// Looking for the key of the path within the innermost object.
+    Seeking { first_member: bool },
+    /// Within the array, waiting for the next element.
+    InArray { first_element: bool },
+    /// Reading the rest of the open objects, after the array (or after the key was not found).
+    Closing,
+}
+
+/// Split an array within the input into separated inputs while it is being parsed, so that only one element is
+/// kept in memory.
+///
+/// The path is a list of keys (like `.data.items`); `.` means the top level value is the array. Inputs that do
+/// not have an array in that path are ignored.
+#[derive(Clone)]
+pub struct StreamSplitter {
+    path: Vec<String>,
+    found: usize,
+    open_objects: usize,
+    state: State,
+}
+
+impl FromStr for StreamSplitter {
+    type Err = StreamSplitterParseError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let Some(keys) = s.trim().strip_prefix('.') else {
+            return Err(StreamSplitterParseError::MissingDot(s.to_string()));
+        };
+        let mut path = Vec::new();
+        if !keys.is_empty() {
+            for key in keys.split('.') {
+                if key.is_empty() {
+                    return Err(StreamSplitterParseError::EmptyKey(s.to_string()));
+                }
+                path.push(key.to_string());
+            }
+        }
+        Ok(StreamSplitter {
+            path,
+            found: 0,
+            open_objects: 0,
+            state: State::Start,
+        })
+    }
+}
+
+impl StreamSplitter {
+    pub fn explain(&self) -> Explanation {
+        let path: Vec<_> = self.path.iter().map(|key| format!(".{key}")).collect();
+        let path = if path.is_empty() {
+            ".".to_string()
+        } else {
+            path.concat()
+        };
+        Explanation::new(format!("stream the elements of `{path}`"))
+    }
+
+    /// Start again from the next top level value (after an error).
+    pub fn reset(&mut self) {
+        self.found = 0;
+        self.open_objects = 0;
+        self.state = State::Start;
+    }
+
+    /// Read the input up to the start of the next element of the array, and return `false` if there are no more
+    /// elements.
+    pub fn seek<R: Read>(&mut self, reader: &mut Reader<R>) -> Result<bool> {
+        loop {
+            reader.eat_whitespace()?;
+            match self.state {
+                State::Start => {
+                    reader.eat_record_separators()?;
+                    let Some(ch) = reader.peek()? else {
+                        return Ok(false);
+                    };
+                    self.found = 0;
+                    self.open_objects = 0;
+                    self.enter(reader, ch)?;
+                }
+                State::Seeking { first_member } => {
+                    if !self.next_member(reader, first_member)? {
+                        self.state = State::Closing;
+                        continue;
+                    }
+                    let key = read_key(reader)?;
+                    if key == self.path[self.found] {
+                        self.found += 1;
+                        reader.eat_whitespace()?;
+                        match reader.peek()? {
+                            Some(ch) => self.enter(reader, ch)?,
+                            None => {
+                                return Err(JsonParserError::UnexpectedEof(reader.where_am_i()));
+                            }
+                        }
+                    } else {
+                        skip_value(reader)?;
+                        self.state = State::Seeking {
+                            first_member: false,
+                        };
+                    }
+                }
+                State::InArray { first_element } => match reader.peek()? {
+                    Some(b']') => {
+                        reader.next()?;
+                        self.state = State::Closing;
+                    }
+                    Some(b',') if !first_element => {
+                        reader.next()?;
+                        reader.eat_whitespace()?;
+                        self.state = State::InArray {
+                            first_element: false,
+                        };
+                        return Ok(true);
+                    }
+                    Some(_) if first_element => {
+                        self.state = State::InArray {
+                            first_element: false,
+                        };
+                        return Ok(true);
+                    }
+                    Some(ch) => return Err(unexpected(reader, ch, ",, ]")),
+                    None => return Err(JsonParserError::UnexpectedEof(reader.where_am_i())),
+                },
+                State::Closing => {
+                    if self.open_objects == 0 {
+                        self.state = State::Start;
+                    } else if self.next_member(reader, false)? {
+                        read_key(reader)?;
+                        skip_value(reader)?;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Enter the value that starts with `ch`, if it's the next step of the path (or skip it if it is not).
+    fn enter<R: Read>(&mut self, reader: &mut Reader<R>, ch: u8) -> Result<()> {
+        if self.found == self.path.len() && ch == b'[' {
+            reader.next()?;
+            self.state = State::InArray {
+                first_element: true,
+            };
+        } else if self.found < self.path.len() && ch == b'{' {
+            reader.next()?;
+            self.open_objects += 1;
+            self.state = State::Seeking { first_member: true };
+        } else {
+            skip_value(reader)?;
+            self.state = State::Closing;
+        }
+        Ok(())
+    }
+
+    /// Move to the next member of the innermost object, and return `false` if the object was closed.
+    fn next_member<R: Read>(&mut self, reader: &mut Reader<R>, first_member: bool) -> Result<bool> {
+        match reader.peek()? {
+            Some(b'}') => {
+                reader.next()?;
+                self.open_objects -= 1;
+                Ok(false)
+            }
+            Some(b',') if !first_member => {
+                reader.next()?;
+                Ok(true)
+            }
+            Some(_) if first_member => Ok(true),
+            Some(ch) => Err(unexpected(reader, ch, ",, }")),
+            None => Err(JsonParserError::UnexpectedEof(reader.where_am_i())),
+        }
+    }
+}
+
+fn read_key<R: Read>(reader: &mut Reader<R>) -> Result<String> {
+    let key = match reader.next_json_value()? {
+        Some(JsonValue::String(key)) => key,
+        Some(value) => {
+            return Err(JsonParserError::StringKeyMissing(
+                reader.where_am_i(),
+                value.type_name(),
+            ));
+        }
+        None => return Err(JsonParserError::UnexpectedEof(reader.where_am_i())),
+    };
+    reader.eat_whitespace()?;
+    match reader.peek()? {
+        Some(b':') => {
+            reader.next()?;
+            Ok(key)
+        }
+        Some(ch) => Err(unexpected(reader, ch, ":")),
+        None => Err(JsonParserError::UnexpectedEof(reader.where_am_i())),
+    }
+}
+
+/// Skip the next value without building it, so a large value that is not on the path is not kept in memory.
+///
+/// Only the brackets and the strings are checked, the scalars are skipped up to the next delimiter.
+fn skip_value<R: Read>(reader: &mut Reader<R>) -> Result<()> {
+    reader.eat_whitespace()?;
+    let mut closing = Vec::new();
+    loop {
+        match reader.peek()? {
+            None => return Err(JsonParserError::UnexpectedEof(reader.where_am_i())),
+            Some(RECORD_SEPARATOR) => {
+                return Err(JsonParserError::UnexpectedRecordSeparator(
+                    reader.where_am_i(),
+                ));
+            }
+            Some(b'{') => {
+                reader.next()?;
+                closing.push(b'}');
+                continue;
+            }
+            Some(b'[') => {
+                reader.next()?;
+                closing.push(b']');
+                continue;
+            }
+            Some(ch @ (b'}' | b']')) => {
+                if closing.pop() != Some(ch) {
+                    return Err(unexpected(reader, ch, "a value"));
+                }
+                reader.next()?;
+            }
+            Some(b',' | b':' | b' ' | b'\n' | b'\t' | b'\r') if !closing.is_empty() => {
+                reader.next()?;
+                continue;
+            }
+            Some(b'"') => skip_string(reader)?,
+            Some(b'-' | b'0'..=b'9' | b't' | b'f' | b'n') => {
+                while let Some(b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z' | b'.' | b'+' | b'-') =
+                    reader.next()?
+                {}
+            }
+            Some(ch) => return Err(unexpected(reader, ch, "a value")),
+        }
+        if closing.is_empty() {
+            return Ok(());
+        }
+    }
+}
+
+fn skip_string<R: Read>(reader: &mut Reader<R>) -> Result<()> {
+    loop {
+        match reader.next()? {
+            None => return Err(JsonParserError::UnexpectedEof(reader.where_am_i())),
+            Some(b'\\') => {
+                reader.next()?;
+            }
+            Some(b'"') => {
+                reader.next()?;
+                return Ok(());
+            }
+            Some(_) => {}
+        }
+    }
+}
+
+fn unexpected<R: Read>(reader: &Reader<R>, ch: u8, expected: &str) -> JsonParserError {
+    JsonParserError::UnexpectedCharacter(reader.where_am_i(), ch as char, expected.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::reader::from_string;
+
+    use super::*;
+
+    fn stream(path: &str, input: &str) -> Result<Vec<String>> {
+        let mut splitter = StreamSplitter::from_str(path).unwrap();
+        let input = input.to_string();
+        let mut reader = from_string(&input);
+        let mut elements = vec![];
+        while splitter.seek(&mut reader)? {
+            if let Some(value) = reader.next_json_value()? {
+                elements.push(value.to_string());
+            }
+        }
+        Ok(elements)
+    }
+
+    #[test]
+    fn from_str_parse_the_path() {
+        assert_eq!(
+            StreamSplitter::from_str(".data.items").unwrap().path,
+            vec!["data", "items"]
+        );
+        assert!(StreamSplitter::from_str(".").unwrap().path.is_empty());
+        assert!(StreamSplitter::from_str("data").is_err());
+        assert!(StreamSplitter::from_str(".data..items").is_err());
+    }
+
+    #[test]
+    fn stream_top_level_array() -> Result<()> {
+        let elements = stream(".", r#"[1, {"a": [2]}, "three"] [] [4]"#)?;
+
+        assert_eq!(elements, vec!["1", r#"{"a": [2]}"#, r#""three""#, "4"]);
+        Ok(())
+    }
+
+    #[test]
+    fn stream_nested_array() -> Result<()> {
+        let input = r#"
+            {"count": 2, "data": {"skip": [0], "items": [{"id": 1}, {"id": 2}], "more": {"a": 1}}, "end": true}
+            {"data": {"items": [3]}}
+        "#;
+
+        let elements = stream(".data.items", input)?;
+
+        assert_eq!(elements, vec![r#"{"id": 1}"#, r#"{"id": 2}"#, "3"]);
+        Ok(())
+    }
+
+    #[test]
+    fn stream_ignore_values_without_array_in_the_path() -> Result<()> {
+        let input = r#"[1] {"data": 3} {"other": [4]} {"data": {"items": {"a": 1}}} {"data": {"items": [5]}}"#;
+
+        let elements = stream(".data.items", input)?;
+
+        assert_eq!(elements, vec!["5"]);
+        Ok(())
+    }
+
+    #[test]
+    fn stream_skip_large_siblings_without_parsing_them() -> Result<()> {
+        let input = r#"{"meta": {"text": "a \"}]\\", "list": [1.5e3, -2, true, null, {}]}, "n": 12, "items": [1, 2]}"#;
+
+        let elements = stream(".items", input)?;
+
+        assert_eq!(elements, vec!["1", "2"]);
+        assert!(stream(".items", r#"{"meta": [1}, "items": [1]}"#).is_err());
+        assert!(stream(".items", r#"{"meta": "open, "items": [1]}"#).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn stream_fail_on_invalid_input() {
+        assert!(stream(".results", r#"{"results": [1 2]}"#).is_err());
+    }
+
+    #[test]
+    fn explain_show_the_path() {
+        let splitter = StreamSplitter::from_str(".data.items").unwrap();
+
+        assert_eq!(
+            splitter.explain().to_string(),
+            "stream the elements of `.data.items`\n"
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use std::{cell::RefCell, rc::Rc};
+
+use thiserror::Error;
+
+use crate::reader::Location;
+
+#[derive(Debug, Error)]
+pub enum EvaluationError {
+    #[error("Function `{function}` returned nothing, {reason}. Usage: {signature}")]
+    FunctionReturnedNothing {
+        function: String,
+        signature: String,
+        reason: String,
+    },
+    #[error("`{path}` expected {expected} for `{step}`, got {actual}")]
+    UnexpectedType {
+        path: String,
+        step: String,
+        expected: &'static str,
+        actual: String,
+    },
+    #[error("`{path}` key `{key}` not found")]
+    MissingKey { path: String, key: String },
+    #[error("`{path}` index {index} is out of bounds, the array has {length} items")]
+    IndexOutOfBounds {
+        path: String,
+        index: usize,
+        length: usize,
+    },
+    #[error("Variable `:{0}` is not defined")]
+    UnknownVariable(String),
+    #[error("Macro `@{0}` is not defined")]
+    UnknownMacro(String),
+    #[error("Selection `/{0}/` is not defined")]
+    UnknownSelection(String),
+    #[error("Input context `&{0}` is not available")]
+    NoInputContext(String),
+    #[error("Invalid regular expression `{0}`: {1}")]
+    InvalidRegex(String, String),
+    #[error("Could not execute `{0}`: {1}")]
+    Exec(String, String),
+}
+
+/// An evaluation error, together with the location of the input that caused it.
+#[derive(Debug, Error)]
+pub struct StrictError {
+    location: Option<Location>,
+    error: Box<EvaluationError>,
+}
+
+impl StrictError {
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+}
+
+impl std::fmt::Display for StrictError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{location}: {}", self.error),
+            None => write!(f, "{}", self.error),
+        }
+    }
+}
+
+/// Collects the reasons for selections that produced nothing, when running with `--strict`.
+///
+/// A getter that fails reports its error. A function that still returns a value discards the errors of its
+/// arguments (for example the missing key in `(default .a 1)`), so only the errors that caused nothing to be
+/// produced are left.
+#[derive(Clone, Default)]
+pub struct StrictErrors {
+    errors: Rc<RefCell<Vec<StrictError>>>,
+}
+
+impl StrictErrors {
+    pub fn report(&self, location: Option<Location>, error: EvaluationError) {
+        self.errors.borrow_mut().push(StrictError {
+            location,
+            error: Box::new(error),
+        });
+    }
+
+    pub fn mark(&self) -> usize {
+        self.errors.borrow().len()
+    }
+
+    pub fn has_errors_since(&self, mark: usize) -> bool {
+        self.errors.borrow().len() > mark
+    }
+
+    pub fn discard_since(&self, mark: usize) {
+        self.errors.borrow_mut().truncate(mark);
+    }
+
+    pub fn take(&self) -> Vec<StrictError> {
+        self.errors.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc, str::FromStr};
+
+    use super::*;
+    use crate::{
+        json_value::JsonValue,
+        processor::Context,
+        selection::{Get, Selection},
+    };
+
+    fn evaluate(selection: &str, input: &str) -> (Option<JsonValue>, Vec<String>) {
+        let errors = StrictErrors::default();
+        let input = JsonValue::from_str(input).unwrap();
+        let context = Context::new_with_no_context(input).with_strict_errors(&errors);
+        let result = Selection::from_str(selection).unwrap().get(&context);
+        let errors = errors.take().iter().map(ToString::to_string).collect();
+        (result, errors)
+    }
+
+    #[test]
+    fn function_report_the_argument_type() {
+        let (result, errors) = evaluate("(abs .a)", r#"{"a": "text"}"#);
+
+        assert_eq!(result, None);
+        assert_eq!(
+            errors,
+            vec![
+                "Function `abs` returned nothing, argument 0 expected number, got string. Usage: (abs <arg0>)"
+            ]
+        );
+    }
+
+    #[test]
+    fn function_report_only_the_inner_error() {
+        let (result, errors) = evaluate("(abs .b)", r#"{"a": 1}"#);
+
+        assert_eq!(result, None);
+        assert_eq!(errors, vec!["`.b` key `b` not found"]);
+    }
+
+    #[test]
+    fn function_discard_errors_if_it_returned_a_value() {
+        let (result, errors) = evaluate("(default .b 1)", r#"{"a": 1}"#);
+
+        assert_eq!(result, Some(1.into()));
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn function_evaluate_each_argument_once() {
+        struct Counted(Rc<Cell<usize>>);
+        impl Get for Counted {
+            fn get(&self, _: &Context) -> Option<JsonValue> {
+                self.0.set(self.0.get() + 1);
+                Some("text".into())
+            }
+        }
+        let count = Rc::new(Cell::new(0));
+        let counted: Rc<dyn Get> = Rc::new(Counted(count.clone()));
+        let errors = StrictErrors::default();
+        let context = Context::new_with_no_context(JsonValue::Null)
+            .with_definition("counted".into(), &counted)
+            .with_strict_errors(&errors);
+
+        let result = Selection::from_str("(abs @counted)").unwrap().get(&context);
+
+        assert_eq!(result, None);
+        let errors: Vec<_> = errors.take().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            errors,
+            vec![
+                "Function `abs` returned nothing, argument 0 expected number, got string. Usage: (abs <arg0>)"
+            ]
+        );
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn discard_since_remove_only_the_new_errors() {
+        let errors = StrictErrors::default();
+        errors.report(None, EvaluationError::UnknownVariable("one".into()));
+        let mark = errors.mark();
+        errors.report(None, EvaluationError::UnknownVariable("two".into()));
+
+        assert!(errors.has_errors_since(mark));
+        errors.discard_since(mark);
+        assert!(!errors.has_errors_since(mark));
+
+        let errors: Vec<_> = errors.take().iter().map(ToString::to_string).collect();
+        assert_eq!(errors, vec!["Variable `:one` is not defined"]);
+    }
+
+    #[test]
+    fn display_add_the_location() {
+        let location = Location {
+            input: Some("file.json".into()),
+            line_number: 3,
+            char_number: 4,
+        };
+        let error = StrictError {
+            location: Some(location),
+            error: Box::new(EvaluationError::UnknownMacro("name".into())),
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "file.json:3:4: Macro `@name` is not defined"
+        );
+    }
+}
@@ -0,0 +1,314 @@
+use std::{
+    cell::RefCell,
+    fs::File,
+    io::{BufWriter, Error as IoError, Write},
+    path::PathBuf,
+    rc::Rc,
+};
+
+use clap::ValueEnum;
+use thiserror::Error;
+
+use crate::{
+    explain::Explanation,
+    output_style::{OutputOptions, OutputStyle, OutputStyleValidationError},
+    processor::{Context, Process, ProcessDecision, Result as ProcessResult, Titles},
+};
+
+#[derive(Debug, Error)]
+pub enum TeeParserError {
+    #[error("invalid STYLE:file: no `:` found in `{0}`")]
+    NoColonError(String),
+    #[error("Unknown output style `{0}`")]
+    UnknownStyle(String),
+    #[error("Empty file name in `{0}`")]
+    EmptyFileName(String),
+    #[error("{0}")]
+    Io(#[from] IoError),
+    #[error("{0}")]
+    OutputStyle(#[from] OutputStyleValidationError),
+}
+
+pub struct Tee {
+    output_options: OutputOptions,
+    file: PathBuf,
+}
+
+impl Tee {
+    /// Parse a `<output-style>:<file>` spec. The sink inherits the main output options, with its own style.
+    fn parse(s: &str, output_options: &OutputOptions) -> Result<Self, TeeParserError> {
+        let pos = s
+            .find(':')
+            .ok_or_else(|| TeeParserError::NoColonError(s.to_string()))?;
+        let style = s[..pos].trim();
+        let output_style = OutputStyle::from_str(style, true)
+            .map_err(|_| TeeParserError::UnknownStyle(style.to_string()))?;
+        let file = &s[pos + 1..];
+        if file.is_empty() {
+            return Err(TeeParserError::EmptyFileName(s.to_string()));
+        }
+        Ok(Tee {
+            output_options: output_options.restyle(output_style),
+            file: file.into(),
+        })
+    }
+
+    fn explain(&self) -> Explanation {
+        Explanation::new(format!("tee into `{}`", self.file.display()))
+            .with_child(self.output_options.explain())
+    }
+
+    fn create_process(&self, flush_each_record: bool) -> Result<Box<dyn Process>, TeeParserError> {
+        let file = File::create(&self.file)?;
+        let writer = Rc::new(RefCell::new(BufWriter::new(file)));
+        let output = self.output_options.get_processor(writer.clone())?;
+        Ok(Box::new(FileOutputProcess {
+            writer,
+            output,
+            flush_each_record,
+        }))
+    }
+}
+
+pub fn explain_tee(
+    tees: &[String],
+    output_options: &OutputOptions,
+) -> Result<Vec<Explanation>, TeeParserError> {
+    tees.iter()
+        .map(|tee| Ok(Tee::parse(tee, output_options)?.explain()))
+        .collect()
+}
+
+pub fn create_tee_process(
+    tees: &[String],
+    output_options: &OutputOptions,
+    flush_each_record: bool,
+    next: Box<dyn Process>,
+) -> Result<Box<dyn Process>, TeeParserError> {
+    if tees.is_empty() {
+        return Ok(next);
+    }
+    let mut outputs = Vec::with_capacity(tees.len() + 1);
+    outputs.push(next);
+    for tee in tees {
+        let tee = Tee::parse(tee, output_options)?;
+        outputs.push(tee.create_process(flush_each_record)?);
+    }
+    Ok(Box::new(TeeProcess { outputs }))
+}
+
+struct FileOutputProcess {
+    writer: Rc<RefCell<BufWriter<File>>>,
+    output: Box<dyn Process>,
+    flush_each_record: bool,
+}
+
+impl Process for FileOutputProcess {
+    fn start(&mut self, titles_so_far: Titles) -> ProcessResult<()> {
+        self.output.start(titles_so_far)
+    }
+    fn process(&mut self, context: Context) -> ProcessResult<ProcessDecision> {
+        let decision = self.output.process(context)?;
+        if self.flush_each_record {
+            self.writer.borrow_mut().flush()?;
+        }
+        Ok(decision)
+    }
+    fn complete(&mut self) -> ProcessResult<()> {
+        self.output.complete()?;
+        self.writer.borrow_mut().flush()?;
+        Ok(())
+    }
+}
+
+struct TeeProcess {
+    outputs: Vec<Box<dyn Process>>,
+}
+
+impl Process for TeeProcess {
+    fn start(&mut self, titles_so_far: Titles) -> ProcessResult<()> {
+        for output in &mut self.outputs {
+            output.start(titles_so_far.clone())?;
+        }
+        Ok(())
+    }
+    fn process(&mut self, context: Context) -> ProcessResult<ProcessDecision> {
+        let mut decision = ProcessDecision::Break;
+        for output in &mut self.outputs {
+            if output.process(context.clone())? == ProcessDecision::Continue {
+                decision = ProcessDecision::Continue;
+            }
+        }
+        Ok(decision)
+    }
+    fn complete(&mut self) -> ProcessResult<()> {
+        for output in &mut self.outputs {
+            output.complete()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::{read_to_string, remove_file};
+
+    use super::*;
+    use crate::json_value::JsonValue;
+
+    #[test]
+    fn parse_parse_correctly() {
+        let tee = Tee::parse("CSV:out.csv", &OutputOptions::with_style(OutputStyle::Json)).unwrap();
+
+        assert_eq!(tee.file, PathBuf::from("out.csv"));
+        assert_eq!(tee.output_options.file_extension(), "csv");
+    }
+
+    #[test]
+    fn no_colon_return_error() {
+        let error = Tee::parse("out.csv", &OutputOptions::with_style(OutputStyle::Json))
+            .err()
+            .unwrap();
+
+        assert!(matches!(error, TeeParserError::NoColonError(_)));
+    }
+
+    #[test]
+    fn unknown_style_return_error() {
+        let error = Tee::parse("xml:out.xml", &OutputOptions::with_style(OutputStyle::Json))
+            .err()
+            .unwrap();
+
+        assert!(matches!(error, TeeParserError::UnknownStyle(_)));
+    }
+
+    #[test]
+    fn empty_file_return_error() {
+        let error = Tee::parse("json:", &OutputOptions::with_style(OutputStyle::Json))
+            .err()
+            .unwrap();
+
+        assert!(matches!(error, TeeParserError::EmptyFileName(_)));
+    }
+
+    #[test]
+    fn empty_list_return_the_next_process() -> ProcessResult<()> {
+        struct Next(Rc<RefCell<usize>>);
+        impl Process for Next {
+            fn complete(&mut self) -> ProcessResult<()> {
+                Ok(())
+            }
+            fn start(&mut self, _: Titles) -> ProcessResult<()> {
+                Ok(())
+            }
+            fn process(&mut self, _: Context) -> ProcessResult<ProcessDecision> {
+                *self.0.borrow_mut() += 1;
+                Ok(ProcessDecision::Break)
+            }
+        }
+        let data = Rc::new(RefCell::new(0));
+        let list: Vec<String> = vec![];
+
+        let mut process = create_tee_process(
+            &list,
+            &OutputOptions::with_style(OutputStyle::Json),
+            false,
+            Box::new(Next(data.clone())),
+        )
+        .unwrap();
+        let decision = process.process(Context::new_with_no_context(JsonValue::Null))?;
+
+        assert_eq!(decision, ProcessDecision::Break);
+        assert_eq!(*data.borrow(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn process_will_write_to_all_the_outputs() -> ProcessResult<()> {
+        struct Next(Rc<RefCell<usize>>);
+        impl Process for Next {
+            fn complete(&mut self) -> ProcessResult<()> {
+                Ok(())
+            }
+            fn start(&mut self, titles: Titles) -> ProcessResult<()> {
+                assert_eq!(titles.len(), 1);
+                Ok(())
+            }
+            fn process(&mut self, _: Context) -> ProcessResult<ProcessDecision> {
+                *self.0.borrow_mut() += 1;
+                Ok(ProcessDecision::Continue)
+            }
+        }
+        let data = Rc::new(RefCell::new(0));
+        let dir = temp_dir();
+        let csv = dir.join(format!("jawk-tee-{}.csv", std::process::id()));
+        let json = dir.join(format!("jawk-tee-{}.ndjson", std::process::id()));
+        let list = vec![
+            format!("csv:{}", csv.display()),
+            format!("json:{}", json.display()),
+        ];
+        let name = Rc::new("name".to_string());
+
+        let mut process = create_tee_process(
+            &list,
+            &OutputOptions::with_style(OutputStyle::Json),
+            false,
+            Box::new(Next(data.clone())),
+        )
+        .unwrap();
+        process.start(Titles::default().with_title(&name))?;
+        for value in ["one", "two"] {
+            let context = Context::new_with_no_context(JsonValue::Null)
+                .with_result(&name, Some(value.into()));
+            process.process(context)?;
+        }
+        process.complete()?;
+
+        assert_eq!(*data.borrow(), 2);
+        assert_eq!(read_to_string(&csv)?, "\"name\"\n\"one\"\n\"two\"\n");
+        assert_eq!(
+            read_to_string(&json)?,
+            "{\"name\": \"one\"}\n{\"name\": \"two\"}\n"
+        );
+        remove_file(csv)?;
+        remove_file(json)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn process_can_flush_each_record() -> ProcessResult<()> {
+        struct Next;
+        impl Process for Next {
+            fn complete(&mut self) -> ProcessResult<()> {
+                Ok(())
+            }
+            fn start(&mut self, _: Titles) -> ProcessResult<()> {
+                Ok(())
+            }
+            fn process(&mut self, _: Context) -> ProcessResult<ProcessDecision> {
+                Ok(ProcessDecision::Continue)
+            }
+        }
+        let json = temp_dir().join(format!("jawk-tee-flush-{}.ndjson", std::process::id()));
+        let list = vec![format!("json:{}", json.display())];
+
+        let mut process = create_tee_process(
+            &list,
+            &OutputOptions::with_style(OutputStyle::Json),
+            true,
+            Box::new(Next),
+        )
+        .unwrap();
+        process.start(Titles::default())?;
+        process.process(Context::new_with_no_context(1.into()))?;
+
+        assert_eq!(read_to_string(&json)?, "1\n");
+        process.complete()?;
+        remove_file(json)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,504 @@
+use std::{iter::Peekable, str::Chars, str::FromStr};
+
+use thiserror::Error;
+
+use crate::json_value::{JsonValue, NumberValue};
+
+/// The largest precision of a placeholder, as Rust's float formatting can not produce many more digits.
+const MAX_PRECISION: usize = 1000;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum TemplateError {
+    #[error("Template `{0}` has a placeholder that was not closed")]
+    Unclosed(String),
+    #[error("Template `{0}` has an unmatched `}}`, use `}}}}` for a literal `}}`")]
+    UnmatchedClose(String),
+    #[error("Template `{0}` has an invalid placeholder `{{{1}}}`")]
+    InvalidPlaceholder(String, String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Part {
+    Literal(String),
+    Placeholder(Key, Spec),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Key {
+    Next,
+    Index(usize),
+    Name(Vec<String>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Align {
+    Left,
+    Right,
+    Center,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Display,
+    LowerHex,
+    UpperHex,
+    Octal,
+    Binary,
+    Exponent,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Spec {
+    fill: char,
+    align: Option<Align>,
+    sign: bool,
+    zero: bool,
+    width: usize,
+    precision: Option<usize>,
+    kind: Kind,
+}
+
+impl Default for Spec {
+    fn default() -> Self {
+        Spec {
+            fill: ' ',
+            align: None,
+            sign: false,
+            zero: false,
+            width: 0,
+            precision: None,
+            kind: Kind::Display,
+        }
+    }
+}
+
+/// A string template, with placeholders like `{name}`, `{0}` or `{}` that are replaced by values.
+///
+/// A placeholder can have a format specification after a colon, similar to the one of Rust's `format!`:
+/// `{name:[[fill]align][+][0][width][.precision][type]}`, where align is one of `<`, `^` or `>`, and type is
+/// one of `x`, `X`, `o`, `b` or `e` (for numbers). The width is at most 65535 and the precision at most 1000. Use
+/// `{{` and `}}` for literal braces.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    parts: Vec<Part>,
+}
+
+impl FromStr for Template {
+    type Err = TemplateError;
+    fn from_str(template: &str) -> Result<Self, Self::Err> {
+        let mut parts = vec![];
+        let mut literal = String::new();
+        let mut chars = template.chars().peekable();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '}' => return Err(TemplateError::UnmatchedClose(template.to_string())),
+                '{' => {
+                    if !literal.is_empty() {
+                        parts.push(Part::Literal(std::mem::take(&mut literal)));
+                    }
+                    let placeholder = read_placeholder(&mut chars)
+                        .ok_or_else(|| TemplateError::Unclosed(template.to_string()))?;
+                    let part = parse_placeholder(&placeholder).ok_or_else(|| {
+                        TemplateError::InvalidPlaceholder(template.to_string(), placeholder)
+                    })?;
+                    parts.push(part);
+                }
+                ch => literal.push(ch),
+            }
+        }
+        if !literal.is_empty() {
+            parts.push(Part::Literal(literal));
+        }
+        Ok(Template { parts })
+    }
+}
+
+impl Template {
+    /// Render the template. Named placeholders are looked up in `named` (that should be an object) and
+    /// positional ones in `positional`. Return nothing if a value is missing or can not be formatted.
+    pub fn render(
+        &self,
+        named: Option<&JsonValue>,
+        positional: &[Option<JsonValue>],
+    ) -> Option<String> {
+        let mut rendered = String::new();
+        let mut next = 0;
+        for part in &self.parts {
+            match part {
+                Part::Literal(literal) => rendered.push_str(literal),
+                Part::Placeholder(key, spec) => {
+                    let value = match key {
+                        Key::Next => {
+                            next += 1;
+                            positional.get(next - 1)?.as_ref()?
+                        }
+                        Key::Index(index) => positional.get(*index)?.as_ref()?,
+                        Key::Name(path) => lookup(named?, path)?,
+                    };
+                    rendered.push_str(&spec.format(value)?);
+                }
+            }
+        }
+        Some(rendered)
+    }
+}
+
+fn read_placeholder(chars: &mut Peekable<Chars>) -> Option<String> {
+    let mut placeholder = String::new();
+    for ch in chars.by_ref() {
+        if ch == '}' {
+            return Some(placeholder);
+        }
+        placeholder.push(ch);
+    }
+    None
+}
+
+fn parse_placeholder(placeholder: &str) -> Option<Part> {
+    let (key, spec) = match placeholder.split_once(':') {
+        Some((key, spec)) => (key.trim(), parse_spec(spec)?),
+        None => (placeholder.trim(), Spec::default()),
+    };
+    let key = if key.is_empty() {
+        Key::Next
+    } else if let Ok(index) = key.parse() {
+        Key::Index(index)
+    } else {
+        let path: Vec<String> = key.split('.').map(ToString::to_string).collect();
+        if path
+            .iter()
+            .any(|name| name.is_empty() || name.contains('{'))
+        {
+            return None;
+        }
+        Key::Name(path)
+    };
+    Some(Part::Placeholder(key, spec))
+}
+
+fn parse_spec(spec: &str) -> Option<Spec> {
+    fn align(ch: char) -> Option<Align> {
+        match ch {
+            '<' => Some(Align::Left),
+            '>' => Some(Align::Right),
+            '^' => Some(Align::Center),
+            _ => None,
+        }
+    }
+    let mut parsed = Spec::default();
+    let chars: Vec<char> = spec.chars().collect();
+    let mut index = 0;
+    if let Some(second) = chars.get(1).and_then(|ch| align(*ch)) {
+        parsed.fill = chars[0];
+        parsed.align = Some(second);
+        index = 2;
+    } else if let Some(first) = chars.first().and_then(|ch| align(*ch)) {
+        parsed.align = Some(first);
+        index = 1;
+    }
+    if chars.get(index) == Some(&'+') {
+        parsed.sign = true;
+        index += 1;
+    }
+    if chars.get(index) == Some(&'0') {
+        parsed.zero = true;
+        index += 1;
+    }
+    let (width, next) = read_number(&chars, index);
+    if next > index {
+        parsed.width = width?;
+    }
+    index = next;
+    if chars.get(index) == Some(&'.') {
+        let (precision, next) = read_number(&chars, index + 1);
+        parsed.precision = Some(precision.filter(|precision| *precision <= MAX_PRECISION)?);
+        index = next;
+    }
+    if let Some(kind) = chars.get(index) {
+        parsed.kind = match kind {
+            'x' => Kind::LowerHex,
+            'X' => Kind::UpperHex,
+            'o' => Kind::Octal,
+            'b' => Kind::Binary,
+            'e' => Kind::Exponent,
+            _ => return None,
+        };
+        index += 1;
+    }
+    if index == chars.len() {
+        Some(parsed)
+    } else {
+        None
+    }
+}
+
+/// Read a width or a precision. Like in Rust's `format!`, they are limited to 16 bits, so a template can not
+/// allocate an unbounded amount of memory (the precision is further limited to `MAX_PRECISION`).
+fn read_number(chars: &[char], start: usize) -> (Option<usize>, usize) {
+    let mut end = start;
+    while chars.get(end).is_some_and(char::is_ascii_digit) {
+        end += 1;
+    }
+    let number = chars[start..end]
+        .iter()
+        .collect::<String>()
+        .parse::<u16>()
+        .ok()
+        .map(usize::from);
+    (number, end)
+}
+
+fn lookup<'a>(value: &'a JsonValue, path: &[String]) -> Option<&'a JsonValue> {
+    let mut value = value;
+    for name in path {
+        value = match value {
+            JsonValue::Object(map) => map.get(name)?,
+            JsonValue::Array(list) => list.get(name.parse::<usize>().ok()?)?,
+            _ => return None,
+        };
+    }
+    Some(value)
+}
+
+impl Spec {
+    fn format(&self, value: &JsonValue) -> Option<String> {
+        match value {
+            JsonValue::Number(number) => self.format_number(number),
+            _ if self.kind != Kind::Display => None,
+            JsonValue::String(str) => {
+                let str = match self.precision {
+                    Some(precision) => str.chars().take(precision).collect(),
+                    None => str.clone(),
+                };
+                Some(self.pad(&str, Align::Left))
+            }
+            value => Some(self.pad(&value.to_string(), Align::Left)),
+        }
+    }
+
+    fn format_number(&self, number: &NumberValue) -> Option<String> {
+        let (negative, digits) = match (self.kind, number) {
+            (Kind::Display, NumberValue::Float(f)) => match self.precision {
+                Some(precision) => (*f < 0.0, format!("{:.precision$}", f.abs())),
+                None => (*f < 0.0, f.abs().to_string()),
+            },
+            (Kind::Exponent, NumberValue::Float(f)) => match self.precision {
+                Some(precision) => (*f < 0.0, format!("{:.precision$e}", f.abs())),
+                None => (*f < 0.0, format!("{:e}", f.abs())),
+            },
+            (kind, number) => {
+                let (negative, n) = match number {
+                    NumberValue::Positive(n) => (false, *n),
+                    NumberValue::Negative(n) => (*n < 0, n.unsigned_abs()),
+                    NumberValue::Float(_) => return None,
+                };
+                let digits = match kind {
+                    Kind::Display => match self.precision {
+                        Some(precision) if precision > 0 => {
+                            format!("{n}.{}", "0".repeat(precision))
+                        }
+                        _ => n.to_string(),
+                    },
+                    Kind::Exponent => match self.precision {
+                        Some(precision) => format!("{n:.precision$e}"),
+                        None => format!("{n:e}"),
+                    },
+                    Kind::LowerHex => format!("{n:x}"),
+                    Kind::UpperHex => format!("{n:X}"),
+                    Kind::Octal => format!("{n:o}"),
+                    _ => format!("{n:b}"),
+                };
+                (negative, digits)
+            }
+        };
+        let sign = if negative {
+            "-"
+        } else if self.sign {
+            "+"
+        } else {
+            ""
+        };
+        if self.zero && self.align.is_none() {
+            let zeros = self
+                .width
+                .saturating_sub(sign.len() + digits.chars().count());
+            Some(format!("{sign}{}{digits}", "0".repeat(zeros)))
+        } else {
+            Some(self.pad(&format!("{sign}{digits}"), Align::Right))
+        }
+    }
+
+    fn pad(&self, str: &str, default_align: Align) -> String {
+        let missing = self.width.saturating_sub(str.chars().count());
+        if missing == 0 {
+            return str.to_string();
+        }
+        let (before, after) = match self.align.unwrap_or(default_align) {
+            Align::Left => (0, missing),
+            Align::Right => (missing, 0),
+            Align::Center => (missing / 2, missing - missing / 2),
+        };
+        let fill = |count| std::iter::repeat_n(self.fill, count).collect::<String>();
+        format!("{}{str}{}", fill(before), fill(after))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(template: &str, value: &str, positional: &[&str]) -> Option<String> {
+        let template = Template::from_str(template).unwrap();
+        let value = JsonValue::from_str(value).unwrap();
+        let positional: Vec<_> = positional
+            .iter()
+            .map(|value| JsonValue::from_str(value).ok())
+            .collect();
+        template.render(Some(&value), &positional)
+    }
+
+    #[test]
+    fn render_named_placeholders() {
+        let value = r#"{"name": "Jo", "age": 7, "balance": 12.345, "address": {"city": "Rome"}}"#;
+
+        assert_eq!(
+            render(
+                "{name} is {age:>5} years, balance {balance:.2}, lives in {address.city}",
+                value,
+                &[]
+            ),
+            Some("Jo is     7 years, balance 12.35, lives in Rome".to_string())
+        );
+    }
+
+    #[test]
+    fn render_positional_placeholders() {
+        assert_eq!(
+            render("{} and {}, {1} and {0}", "{}", &["1", r#""two""#]),
+            Some("1 and two, two and 1".to_string())
+        );
+    }
+
+    #[test]
+    fn render_with_width_alignment_and_fill() {
+        assert_eq!(
+            render(
+                "[{0:<5}|{0:^5}|{0:*>5}|{1:05}|{1:+}|{2:<4}]",
+                "{}",
+                &[r#""ab""#, "42", "-3"]
+            ),
+            Some("[ab   | ab  |***ab|00042|+42|-3  ]".to_string())
+        );
+    }
+
+    #[test]
+    fn render_numbers_in_other_radixes() {
+        assert_eq!(
+            render(
+                "{0:x} {0:X} {0:o} {0:b} {1:.1e} {2:04x}",
+                "{}",
+                &["255", "1234.5", "-10"]
+            ),
+            Some("ff FF 377 11111111 1.2e3 -00a".to_string())
+        );
+    }
+
+    #[test]
+    fn render_other_values_as_json() {
+        assert_eq!(
+            render(
+                "{a} {b} {c} {d:.3}",
+                r#"{"a": null, "b": [1, 2], "c": true, "d": "abcdef"}"#,
+                &[]
+            ),
+            Some("null [1, 2] true abc".to_string())
+        );
+    }
+
+    #[test]
+    fn render_nothing_for_missing_or_invalid_values() {
+        assert_eq!(render("{missing}", "{}", &[]), None);
+        assert_eq!(render("{3}", "{}", &["1"]), None);
+        assert_eq!(render("{0:x}", "{}", &["1.5"]), None);
+        assert_eq!(render("{0:x}", "{}", &[r#""a""#]), None);
+    }
+
+    #[test]
+    fn parse_escaped_braces() {
+        assert_eq!(
+            render("{{{a}}}", r#"{"a": 1}"#, &[]),
+            Some("{1}".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_fails_on_invalid_templates() {
+        assert!(matches!(
+            Template::from_str("{a"),
+            Err(TemplateError::Unclosed(_))
+        ));
+        assert!(matches!(
+            Template::from_str("a}"),
+            Err(TemplateError::UnmatchedClose(_))
+        ));
+        assert!(matches!(
+            Template::from_str("{a:z}"),
+            Err(TemplateError::InvalidPlaceholder(_, _))
+        ));
+        assert!(matches!(
+            Template::from_str("{a..b}"),
+            Err(TemplateError::InvalidPlaceholder(_, _))
+        ));
+    }
+
+    #[test]
+    fn parse_fails_on_too_large_width_or_precision() {
+        assert!(matches!(
+            Template::from_str("{0:99999999999}"),
+            Err(TemplateError::InvalidPlaceholder(_, _))
+        ));
+        assert!(matches!(
+            Template::from_str("{0:.65536}"),
+            Err(TemplateError::InvalidPlaceholder(_, _))
+        ));
+        assert_eq!(
+            render("{0:>65535}", "null", &["1"]).map(|str| str.len()),
+            Some(65535)
+        );
+        assert!(matches!(
+            Template::from_str("{0:.1001e}"),
+            Err(TemplateError::InvalidPlaceholder(_, _))
+        ));
+        assert_eq!(
+            render("{0:.1000e}", "null", &["2"]).map(|str| str.len()),
+            Some(1004)
+        );
+        assert_eq!(
+            render("{0:.1000e}", "null", &["2.5"]).map(|str| str.len()),
+            Some(1004)
+        );
+    }
+
+    #[test]
+    fn render_large_integers_exactly() {
+        assert_eq!(
+            render(
+                "{0:.2} {0:e} {0:.3e} {1:.1}",
+                "{}",
+                &["9007199254740993", "-9223372036854775807"]
+            ),
+            Some(
+                "9007199254740993.00 9.007199254740993e15 9.007e15 -9223372036854775807.0"
+                    .to_string()
+            )
+        );
+    }
+}
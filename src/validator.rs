@@ -0,0 +1,89 @@
+use std::{
+    cell::RefCell,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use thiserror::Error;
+
+use crate::{
+    explain::Explanation,
+    json_schema::{SchemaError, Violation, load_schema, validate},
+    json_value::JsonValue,
+    processor::{Context, Process, ProcessDecision, Result as ProcessResult, Titles},
+    reader::Location,
+};
+
+/// An input that did not match the `--validate` schema.
+#[derive(Debug, Error)]
+#[error("{location}: Invalid input, {violation}")]
+pub struct InvalidInput {
+    location: Location,
+    violation: Violation,
+}
+
+/// Drop the inputs that do not match a JSON schema, and keep their violations to be reported.
+pub struct SchemaValidation {
+    path: PathBuf,
+    schema: Rc<JsonValue>,
+    violations: Rc<RefCell<Vec<Violation>>>,
+}
+
+impl SchemaValidation {
+    pub fn load(path: &Path) -> Result<Self, SchemaError> {
+        let schema = Rc::new(load_schema(path)?);
+        Ok(SchemaValidation {
+            path: path.into(),
+            schema,
+            violations: Rc::default(),
+        })
+    }
+
+    pub fn explain(&self) -> Explanation {
+        Explanation::new(format!("validate against `{}`", self.path.display()))
+    }
+
+    pub fn create_process(&self, next: Box<dyn Process>) -> Box<dyn Process> {
+        Box::new(SchemaValidationProcess {
+            schema: self.schema.clone(),
+            violations: self.violations.clone(),
+            next,
+        })
+    }
+
+    /// The violations of the inputs that were dropped since the last call.
+    pub fn take(&self, location: &Location) -> Vec<InvalidInput> {
+        self.violations
+            .take()
+            .into_iter()
+            .map(|violation| InvalidInput {
+                location: location.clone(),
+                violation,
+            })
+            .collect()
+    }
+}
+
+struct SchemaValidationProcess {
+    schema: Rc<JsonValue>,
+    violations: Rc<RefCell<Vec<Violation>>>,
+    next: Box<dyn Process>,
+}
+
+impl Process for SchemaValidationProcess {
+    fn complete(&mut self) -> ProcessResult<()> {
+        self.next.complete()
+    }
+    fn process(&mut self, context: Context) -> ProcessResult<ProcessDecision> {
+        let violations = validate(&self.schema, context.input(), &context);
+        if violations.is_empty() {
+            self.next.process(context)
+        } else {
+            self.violations.borrow_mut().extend(violations);
+            Ok(ProcessDecision::Continue)
+        }
+    }
+    fn start(&mut self, titles: Titles) -> ProcessResult<()> {
+        self.next.start(titles)
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    json_parser::JsonParserError, json_value::JsonValue, processor::Context, reader::Reader,
-    selection::Get, selection::Result,
+    explain::Explanation, json_parser::JsonParserError, json_value::JsonValue, processor::Context,
+    reader::Reader, selection::Get, selection::Result, strict::EvaluationError,
 };
 use std::{io::Read, rc::Rc};
 
@@ -17,8 +17,26 @@ struct VariableExtructor {
 impl Get for VariableExtructor {
     fn get(&self, value: &Context) -> Option<JsonValue> {
         match self.variable_type {
-            Type::Macro => value.get_definition(&self.name).and_then(|f| f.get(value)),
-            Type::Variable => value.get_variable_value(&self.name).cloned(),
+            Type::Macro => {
+                let Some(definition) = value.get_definition(&self.name) else {
+                    value.report(|| EvaluationError::UnknownMacro(self.name.clone()));
+                    return None;
+                };
+                definition.get(value)
+            }
+            Type::Variable => {
+                let variable = value.get_variable_value(&self.name).cloned();
+                if variable.is_none() {
+                    value.report(|| EvaluationError::UnknownVariable(self.name.clone()));
+                }
+                variable
+            }
+        }
+    }
+    fn explain(&self) -> Explanation {
+        match self.variable_type {
+            Type::Macro => Explanation::new(format!("macro `@{}`", self.name)),
+            Type::Variable => Explanation::new(format!("variable `:{}`", self.name)),
         }
     }
 }